@@ -1,305 +1,77 @@
-use criterion::{criterion_group, criterion_main, Criterion};
-
-fn day1_benchmark(c: &mut Criterion) {
-    c.bench_function("Day 1 - Part 1", |b| {
-        b.iter(|| {
-            day01::part1(include_str!("../inputs/day01"));
-        })
-    });
-    c.bench_function("Day 1 - Part 1 - Functional", |b| {
-        b.iter(|| {
-            day01::part1_functional(include_str!("../inputs/day01"));
-        })
-    });
-    c.bench_function("Day 1 - Part 2", |b| {
-        b.iter(|| {
-            day01::part2(include_str!("../inputs/day01"));
-        })
-    });
-    c.bench_function("Day 1 - Part 2 - Functional", |b| {
-        b.iter(|| {
-            day01::part2_functional(include_str!("../inputs/day01"));
-        })
-    });
-}
-
-fn day2_benchmark(c: &mut Criterion) {
-    c.bench_function("Day 2 - Part 1", |b| {
-        b.iter(|| {
-            day02::part1(include_str!("../inputs/day02"));
-        })
-    });
-    c.bench_function("Day 2 - Part 1 with regex", |b| {
-        b.iter(|| {
-            day02::part1_with_regex(include_str!("../inputs/day02"));
-        })
-    });
-    c.bench_function("Day 2 - Part 2", |b| {
-        b.iter(|| {
-            day02::part2(include_str!("../inputs/day02"));
-        })
-    });
-}
-
-fn day3_benchmark(c: &mut Criterion) {
-    let input = inpututils::read_lines("inputs/day03");
-    c.bench_function("Day 3 - Read input", |b| {
-        b.iter(|| {
-            inpututils::read_lines("inputs/day03");
-        })
-    });
-    c.bench_function("Day 3 - Part 1", |b| {
-        b.iter(|| {
-            day03::part1(&input);
-        })
-    });
-    c.bench_function("Day 3 - Part 2", |b| {
-        b.iter(|| {
-            day03::part2(&input);
-        })
-    });
-}
-
-fn day4_benchmark(c: &mut Criterion) {
-    let input = inpututils::read_lines("inputs/day04");
-    c.bench_function("Day 4 - Read input", |b| {
-        b.iter(|| {
-            inpututils::read_lines("inputs/day04");
-        })
-    });
-    c.bench_function("Day 4 - Part 1", |b| {
-        b.iter(|| {
-            day04::part1(&input);
-        })
-    });
-    c.bench_function("Day 4 - Part 2", |b| {
-        b.iter(|| {
-            day04::part2(&input);
-        })
-    });
-}
-
-fn day5_benchmark(c: &mut Criterion) {
-    let input = inpututils::read_lines("inputs/day05");
-    c.bench_function("Day 5- Read input", |b| {
-        b.iter(|| {
-            inpututils::read_lines("inputs/day05");
-        })
-    });
-    c.bench_function("Day 5 - Part 1", |b| {
-        b.iter(|| {
-            day05::part1(&input);
-        })
-    });
-    c.bench_function("Day 5 - Part 2", |b| {
-        b.iter(|| {
-            day05::part2(&input);
-        })
-    });
-}
-
-fn day6_benchmark(c: &mut Criterion) {
-    let input = inpututils::read_comma_separated_as::<u8>("inputs/day06");
-    c.bench_function("Day 6 - Read input", |b| {
-        b.iter(|| {
-            inpututils::read_comma_separated_as::<u8>("inputs/day06");
-        })
-    });
-    c.bench_function("Day 6 - Part 1", |b| {
-        b.iter(|| {
-            day06::part1(&input);
-        })
-    });
-    c.bench_function("Day 6 - Part 2", |b| {
-        b.iter(|| {
-            day06::part2(&input);
-        })
-    });
-}
-
-fn day7_benchmark(c: &mut Criterion) {
-    let input = inpututils::read_comma_separated_as::<u64>("inputs/day07");
-    c.bench_function("Day 7 - Read input", |b| {
-        b.iter(|| {
-            inpututils::read_comma_separated_as::<u64>("inputs/day07");
-        })
-    });
-    let mut pt1_input = inpututils::read_comma_separated_as::<u64>("inputs/day07");
-    c.bench_function("Day 7 - Part 1", |b| {
-        b.iter(|| {
-            day07::part1(&mut pt1_input);
-        })
-    });
-    c.bench_function("Day 7 - Part 2", |b| {
-        b.iter(|| {
-            day07::part2(&input);
-        })
-    });
-}
-
-fn day8_benchmark(c: &mut Criterion) {
-    c.bench_function("Day 8 - Part 1", |b| {
-        b.iter(|| {
-            day08::part1(include_str!("../inputs/day08"));
-        })
-    });
-    c.bench_function("Day 8 - Part 2", |b| {
-        b.iter(|| {
-            day08::part2(include_str!("../inputs/day08"));
-        })
-    });
-}
-
-fn day9_benchmark(c: &mut Criterion) {
-    c.bench_function("Day 9 - Part 1", |b| {
-        b.iter(|| {
-            day09::part1(include_str!("../inputs/day09"));
-        })
-    });
-    c.bench_function("Day 9 - Part 2", |b| {
-        b.iter(|| {
-            day09::part2(include_str!("../inputs/day09"));
-        })
-    });
-}
-
-fn day10_benchmark(c: &mut Criterion) {
-    c.bench_function("Day 10 - Part 1", |b| {
-        b.iter(|| {
-            day10::part1(include_str!("../inputs/day10"));
-        })
-    });
-    c.bench_function("Day 10 - Part 2", |b| {
-        b.iter(|| {
-            day10::part2(include_str!("../inputs/day10"));
-        })
-    });
-}
-
-fn day11_benchmark(c: &mut Criterion) {
-    c.bench_function("Day 11 - Part 1", |b| {
-        b.iter(|| {
-            day11::part1(include_str!("../inputs/day11"));
-        })
-    });
-    c.bench_function("Day 11 - Part 2", |b| {
-        b.iter(|| {
-            day11::part2(include_str!("../inputs/day11"));
-        })
-    });
-}
-
-fn day12_benchmark(c: &mut Criterion) {
-    c.bench_function("Day 12 - Part 1", |b| {
-        b.iter(|| {
-            day12::part1(include_str!("../inputs/day12"));
-        })
-    });
-    c.bench_function("Day 12 - Part 2", |b| {
-        b.iter(|| {
-            day12::part2(include_str!("../inputs/day12"));
-        })
-    });
-}
-
-fn day13_benchmark(c: &mut Criterion) {
-    c.bench_function("Day 13 - Part 1", |b| {
-        b.iter(|| {
-            day13::part1(include_str!("../inputs/day13"));
-        })
-    });
-    c.bench_function("Day 13 - Part 2", |b| {
-        b.iter(|| {
-            day13::part2(include_str!("../inputs/day13"));
-        })
-    });
-}
-
-fn day14_benchmark(c: &mut Criterion) {
-    c.bench_function("Day 14 - Part 1", |b| {
-        b.iter(|| {
-            day14::part1(include_str!("../inputs/day14"));
-        })
-    });
-    c.bench_function("Day 14 - Part 2", |b| {
-        b.iter(|| {
-            day14::part2(include_str!("../inputs/day14"));
-        })
-    });
-}
-
-fn day15_benchmark(c: &mut Criterion) {
-    c.bench_function("Day 15 - Part 1", |b| {
-        b.iter(|| {
-            day15::part1(include_str!("../inputs/day15"));
-        })
-    });
-    c.bench_function("Day 15 - Part 2", |b| {
-        b.iter(|| {
-            day15::part2(include_str!("../inputs/day15"));
-        })
-    });
-}
-
-fn day16_benchmark(c: &mut Criterion) {
-    c.bench_function("Day 16 - Part 1", |b| {
-        b.iter(|| {
-            day16::part1(include_str!("../inputs/day16"));
-        })
-    });
-    c.bench_function("Day 16 - Part 2", |b| {
-        b.iter(|| {
-            day16::part2(include_str!("../inputs/day16"));
-        })
-    });
-}
-
-fn day17_benchmark(c: &mut Criterion) {
-    c.bench_function("Day 17 - Part 1", |b| {
-        b.iter(|| {
-            day17::part1(include_str!("../inputs/day17"));
-        })
-    });
-    c.bench_function("Day 17 - Part 2", |b| {
-        b.iter(|| {
-            day17::part2(include_str!("../inputs/day17"));
-        })
-    });
-}
-
-fn day18_benchmark(c: &mut Criterion) {
-    c.bench_function("Day 18 - Part 1", |b| {
-        b.iter(|| {
-            day18::part1(include_str!("../inputs/day18"));
-        })
-    });
-    c.bench_function("Day 18 - Part 2", |b| {
-        b.iter(|| {
-            day18::part2(include_str!("../inputs/day18"));
-        })
-    });
-}
-
-criterion_group!(
-    benchmark,
-    day1_benchmark,
-    day2_benchmark,
-    day3_benchmark,
-    day4_benchmark,
-    day5_benchmark,
-    day6_benchmark,
-    day7_benchmark,
-    day8_benchmark,
-    day8_benchmark,
-    day9_benchmark,
-    day10_benchmark,
-    day11_benchmark,
-    day12_benchmark,
-    day13_benchmark,
-    day14_benchmark,
-    day15_benchmark,
-    day16_benchmark,
-    day17_benchmark,
-    day18_benchmark,
-);
+use advent_of_code_2021::{find_day, input_names, input_path, DAYS};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// The inputs to benchmark a day with, by name. `BENCH_INPUT` narrows them down: unset, every input of the day; a name
+/// like `example`, the input of that name for the days that have one; a path, that file, like a large generated input.
+/// A file is only an input of one day, so a path needs `BENCH_DAY` to say which; it also narrows names to that day.
+fn inputs(day: u8) -> Vec<(String, PathBuf)> {
+    let bench_day = bench_day();
+    if bench_day.is_some_and(|bench_day| bench_day != day) {
+        return Vec::new();
+    }
+    match env::var("BENCH_INPUT") {
+        Err(_) => input_names(day)
+            .into_iter()
+            .map(|name| {
+                let path = input_path(day, &name);
+                (name, path)
+            })
+            .collect(),
+        Ok(file) if Path::new(&file).is_file() => {
+            if bench_day.is_none() {
+                panic!(
+                    "BENCH_INPUT={} is a file: set BENCH_DAY to the day it is an input of",
+                    file
+                );
+            }
+            let path = PathBuf::from(file);
+            let name = path.file_stem().map_or("file".into(), |name| name.to_string_lossy());
+            vec![(name.into_owned(), path)]
+        }
+        Ok(name) => {
+            let path = input_path(day, &name);
+            if path.is_file() {
+                vec![(name, path)]
+            } else {
+                Vec::new()
+            }
+        }
+    }
+}
+
+fn bench_day() -> Option<u8> {
+    let day = env::var("BENCH_DAY").ok()?;
+    match day.parse() {
+        Ok(day) if find_day(day).is_some() => Some(day),
+        _ => panic!("BENCH_DAY={} is not a solved day", day),
+    }
+}
+
+/// One group per day, so criterion compares parsing, both parts and their variants on every input.
+fn days_benchmark(c: &mut Criterion) {
+    for puzzle in DAYS {
+        let day = puzzle.day();
+        let mut group = c.benchmark_group(format!("day{:02}", day));
+        for (name, path) in inputs(day) {
+            let input = inpututils::read_file(&path)
+                .unwrap_or_else(|error| panic!("cannot read {}: {}", path.display(), error));
+            let parsed = puzzle
+                .parse(&input)
+                .unwrap_or_else(|error| panic!("cannot parse {} for day {}: {}", path.display(), day, error));
+            group.bench_function(BenchmarkId::new("parse", &name), |b| b.iter(|| puzzle.parse(&input)));
+            group.bench_function(BenchmarkId::new("part1", &name), |b| b.iter(|| parsed.part1()));
+            group.bench_function(BenchmarkId::new("part2", &name), |b| b.iter(|| parsed.part2()));
+            for (variant, _) in parsed.variants() {
+                group.bench_function(BenchmarkId::new(variant, &name), |b| {
+                    b.iter(|| parsed.solve_variant(variant))
+                });
+            }
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benchmark, days_benchmark);
 criterion_main!(benchmark);
@@ -1,3 +1,5 @@
+use puzzle::PuzzleError;
+
 /// --- Day 1: Sonar Sweep ---
 /// You're minding your own business on a ship at sea when the overboard alarm goes off! You rush to see if you can help. Apparently, one of the Elves tripped and accidentally sent the sleigh keys flying into the ocean!
 ///
@@ -11,6 +13,7 @@
 ///
 /// For example, suppose you had the following report:
 ///
+/// ```text example part1:7 part2:5
 /// 199
 /// 200
 /// 208
@@ -21,6 +24,7 @@
 /// 269
 /// 260
 /// 263
+/// ```
 /// This report indicates that, scanning outward from the submarine, the sonar sweep found depths of 199, 200, 208, 210, and so on.
 ///
 /// The first order of business is to figure out how quickly the depth increases, just so you know what you're dealing with - you never know if the keys will get carried into deeper water by an ocean current or a fish or something.
@@ -40,26 +44,18 @@
 /// In this example, there are 7 measurements that are larger than the previous measurement.
 ///
 /// How many measurements are larger than the previous measurement?
-pub fn part1(input: &str) -> usize {
-    let depth = input.lines().map(|n| n.parse::<u16>().unwrap()).collect::<Vec<_>>();
-
+pub fn part1(depth: &[u32]) -> Result<usize, PuzzleError> {
     let mut count = 0;
     for i in 1..depth.len() {
         if depth[i] > depth[i - 1] {
             count += 1
         }
     }
-    return count;
+    return Ok(count);
 }
 
-pub fn part1_functional(input: &str) -> usize {
-    input
-        .lines()
-        .map(|n| n.parse::<u16>().unwrap())
-        .collect::<Vec<u16>>()
-        .windows(2)
-        .filter(|&vec| vec[0] < vec[1])
-        .count()
+pub fn part1_functional(depth: &[u32]) -> Result<usize, PuzzleError> {
+    Ok(depth.windows(2).filter(|&vec| vec[0] < vec[1]).count())
 }
 
 /// --- Part Two ---
@@ -94,9 +90,7 @@ pub fn part1_functional(input: &str) -> usize {
 /// In this example, there are 5 sums that are larger than the previous sum.
 ///
 /// Consider sums of a three-measurement sliding window. How many sums are larger than the previous sum?
-pub fn part2(input: &str) -> u32 {
-    let depth = input.lines().map(|n| n.parse::<u32>().unwrap()).collect::<Vec<u32>>();
-
+pub fn part2(depth: &[u32]) -> Result<u32, PuzzleError> {
     let mut count = 0;
     for i in 3..depth.len() {
         let window1 = depth[i - 1] + depth[i - 2] + depth[i - 3];
@@ -105,45 +99,93 @@ pub fn part2(input: &str) -> u32 {
             count += 1
         }
     }
-    return count;
+    return Ok(count);
 }
 
-pub fn part2_functional(input: &str) -> usize {
-    input
-        .lines()
-        .map(|n| n.parse::<u32>().unwrap())
-        .collect::<Vec<u32>>()
+pub fn part2_functional(depth: &[u32]) -> Result<usize, PuzzleError> {
+    Ok(depth
         .windows(3)
         .map(|window| window.iter().sum())
         .collect::<Vec<u32>>()
         .windows(2)
         .filter(|pair| pair[1] > pair[0])
-        .count()
+        .count())
+}
+
+/// A depth measurement per line.
+pub fn parse(input: &str) -> Result<Vec<u32>, PuzzleError> {
+    inpututils::parse_lines(input)
+}
+
+pub struct Day01;
+
+impl puzzle::Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<u32>;
+    type Answer1 = usize;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+
+    fn variants() -> Vec<puzzle::Variant<Self::Input>> {
+        vec![
+            puzzle::Variant {
+                name: "part1_functional",
+                part: puzzle::Part::One,
+                solve: |input| part1_functional(input).map(puzzle::Answer::from),
+            },
+            puzzle::Variant {
+                name: "part2_functional",
+                part: puzzle::Part::Two,
+                solve: |input| part2_functional(input).map(puzzle::Answer::from),
+            },
+        ]
+    }
 }
 
 #[cfg(test)]
 mod tests {
     #[test]
     fn test_part1() {
-        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
-        assert_eq!(crate::part1(input), 7);
+        let input = crate::parse("199\n200\n208\n210\n200\n207\n240\n269\n260\n263").unwrap();
+        assert_eq!(crate::part1(&input), Ok(7));
     }
 
     #[test]
     fn test_part1_functional() {
-        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
-        assert_eq!(crate::part1_functional(input), 7);
+        let input = crate::parse("199\n200\n208\n210\n200\n207\n240\n269\n260\n263").unwrap();
+        assert_eq!(crate::part1_functional(&input), Ok(7));
     }
 
     #[test]
     fn test_part2() {
-        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
-        assert_eq!(crate::part2(input), 5);
+        let input = crate::parse("199\n200\n208\n210\n200\n207\n240\n269\n260\n263").unwrap();
+        assert_eq!(crate::part2(&input), Ok(5));
     }
 
     #[test]
     fn test_part2_functional() {
-        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
-        assert_eq!(crate::part2_functional(input), 5);
+        let input = crate::parse("199\n200\n208\n210\n200\n207\n240\n269\n260\n263").unwrap();
+        assert_eq!(crate::part2_functional(&input), Ok(5));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "199\n200\n2O8";
+        assert_eq!(
+            crate::parse(input),
+            Err(puzzle::PuzzleError::parse(3, 1, "2O8", "invalid digit found in string"))
+        );
     }
 }
@@ -10,12 +10,14 @@
 ///
 /// The submarine seems to already have a planned course (your puzzle input). You should probably figure out where it's going. For example:
 ///
+/// ```text example part1:150 part2:900
 /// forward 5
 /// down 5
 /// forward 8
 /// up 3
 /// down 8
 /// forward 2
+/// ```
 /// Your horizontal position and depth both start at 0. The steps above would then modify them as follows:
 ///
 /// forward 5 adds 5 to your horizontal position, a total of 5.
@@ -27,44 +29,84 @@
 /// After following these instructions, you would have a horizontal position of 15 and a depth of 10. (Multiplying these together produces 150.)
 ///
 /// Calculate the horizontal position and depth you would have after following the planned course. What do you get if you multiply your final horizontal position by your final depth?
-pub fn part1(input: &str) -> i32 {
-    let final_position = input.lines().fold((0, 0), |position, instruction| {
-        let parts = instruction.split(" ").collect::<Vec<&str>>();
-        let direction = parts[0];
-        let quantity = parts[1].parse::<i32>().unwrap();
-        return match direction {
-            "forward" => (position.0 + quantity, position.1),
-            "down" => (position.0, position.1 + quantity),
-            "up" => (position.0, position.1 - quantity),
-            _ => panic!("Unexpected direction"),
-        };
+pub fn part1(commands: &[Command]) -> Result<i32, PuzzleError> {
+    let final_position = commands.iter().fold((0, 0), |position, &command| match command {
+        Command::Forward(quantity) => (position.0 + quantity, position.1),
+        Command::Down(quantity) => (position.0, position.1 + quantity),
+        Command::Up(quantity) => (position.0, position.1 - quantity),
     });
 
-    return final_position.0 * final_position.1;
+    return Ok(final_position.0 * final_position.1);
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+/// The line the command is read from.
+impl Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Command::Forward(quantity) => write!(f, "forward {}", quantity),
+            Command::Down(quantity) => write!(f, "down {}", quantity),
+            Command::Up(quantity) => write!(f, "up {}", quantity),
+        }
+    }
+}
+
+/// A command per line, like `forward 5`.
+pub fn parse(input: &str) -> Result<Vec<Command>, PuzzleError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, instruction)| parse_command(i + 1, instruction))
+        .collect()
+}
+
+fn parse_command(line_number: usize, instruction: &str) -> Result<Command, PuzzleError> {
+    let (direction, quantity) = instruction
+        .split_once(' ')
+        .ok_or_else(|| PuzzleError::parse(line_number, 1, instruction, "expected `<direction> <units>`"))?;
+    let command: fn(i32) -> Command = match direction {
+        "forward" => Command::Forward,
+        "down" => Command::Down,
+        "up" => Command::Up,
+        _ => return Err(PuzzleError::parse(line_number, 1, direction, "unexpected direction")),
+    };
+    let quantity = puzzle::parse_token::<i32>(line_number, instruction, quantity)?;
+    Ok(command(quantity))
 }
 
 use lazy_static::lazy_static;
+use puzzle::PuzzleError;
 use regex::{Captures, Regex};
+use std::fmt::{self, Display};
 
-pub fn part1_with_regex(input: &str) -> i32 {
+pub fn part1_with_regex(input: &str) -> Result<i32, PuzzleError> {
     lazy_static! {
         static ref REGEX: Regex = Regex::new(r"(forward|down|up) (\d+)").unwrap();
     }
-    let final_position = input.lines().fold((0, 0), |position, instruction| {
-        let cap: Captures = REGEX
-            .captures(instruction)
-            .unwrap_or_else(|| panic!("Unexpected line: {}", instruction));
-        let direction = cap.get(1).unwrap().as_str();
-        let quantity = cap.get(2).unwrap().as_str().parse::<i32>().unwrap();
-        return match direction {
-            "forward" => (position.0 + quantity, position.1),
-            "down" => (position.0, position.1 + quantity),
-            "up" => (position.0, position.1 - quantity),
-            _ => panic!("Unexpected direction"),
-        };
-    });
+    let final_position = input
+        .lines()
+        .enumerate()
+        .try_fold((0, 0), |position, (i, instruction)| {
+            let cap: Captures = REGEX
+                .captures(instruction)
+                .ok_or_else(|| PuzzleError::parse(i + 1, 1, instruction, "unexpected line"))?;
+            let direction = cap.get(1).unwrap().as_str();
+            let quantity = puzzle::parse_token::<i32>(i + 1, instruction, cap.get(2).unwrap().as_str())?;
+            return Ok(match direction {
+                "forward" => (position.0 + quantity, position.1),
+                "down" => (position.0, position.1 + quantity),
+                "up" => (position.0, position.1 - quantity),
+                _ => unreachable!("Unexpected direction"),
+            });
+        })?;
 
-    return final_position.0 * final_position.1;
+    return Ok(final_position.0 * final_position.1);
 }
 /// --- Part Two ---
 /// Based on your calculations, the planned course doesn't seem to make any sense. You find the submarine manual and discover that the process is actually slightly more complicated.
@@ -89,20 +131,48 @@ pub fn part1_with_regex(input: &str) -> i32 {
 /// After following these new instructions, you would have a horizontal position of 15 and a depth of 60. (Multiplying these produces 900.)
 ///
 /// Using this new interpretation of the commands, calculate the horizontal position and depth you would have after following the planned course. What do you get if you multiply your final horizontal position by your final depth?
-pub fn part2(input: &str) -> i32 {
-    let final_position = input.lines().fold((0, 0, 0), |position, instruction| {
-        let parts = instruction.split(" ").collect::<Vec<&str>>();
-        let direction = parts[0];
-        let quantity = parts[1].parse::<i32>().unwrap();
-        return match direction {
-            "forward" => (position.0 + quantity, position.1 + position.2 * quantity, position.2),
-            "down" => (position.0, position.1, position.2 + quantity),
-            "up" => (position.0, position.1, position.2 - quantity),
-            _ => panic!("Unexpected direction"),
-        };
+pub fn part2(commands: &[Command]) -> Result<i32, PuzzleError> {
+    let final_position = commands.iter().fold((0, 0, 0), |position, &command| match command {
+        Command::Forward(quantity) => (position.0 + quantity, position.1 + position.2 * quantity, position.2),
+        Command::Down(quantity) => (position.0, position.1, position.2 + quantity),
+        Command::Up(quantity) => (position.0, position.1, position.2 - quantity),
     });
 
-    return final_position.0 * final_position.1;
+    return Ok(final_position.0 * final_position.1);
+}
+
+pub struct Day02;
+
+impl puzzle::Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Command>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+
+    fn variants() -> Vec<puzzle::Variant<Self::Input>> {
+        vec![puzzle::Variant {
+            name: "part1_with_regex",
+            part: puzzle::Part::One,
+            // The regex reads the commands as text, so they are written back as the lines of the input
+            solve: |commands| {
+                let input = commands.iter().map(Command::to_string).collect::<Vec<_>>().join("\n");
+                part1_with_regex(&input).map(puzzle::Answer::from)
+            },
+        }]
+    }
 }
 
 #[cfg(test)]
@@ -115,7 +185,7 @@ forward 8
 up 3
 down 8
 forward 2";
-        assert_eq!(crate::part1(input), 150);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(150));
     }
 
     #[test]
@@ -126,7 +196,7 @@ forward 8
 up 3
 down 8
 forward 2";
-        assert_eq!(crate::part1_with_regex(input), 150);
+        assert_eq!(crate::part1_with_regex(input), Ok(150));
     }
 
     #[test]
@@ -137,6 +207,22 @@ forward 8
 up 3
 down 8
 forward 2";
-        assert_eq!(crate::part2(input), 900);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(900));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            crate::parse("forward 5\nsideways 2"),
+            Err(puzzle::PuzzleError::parse(2, 1, "sideways", "unexpected direction"))
+        );
+        assert_eq!(
+            crate::parse("forward 5\ndown x"),
+            Err(puzzle::PuzzleError::parse(2, 6, "x", "invalid digit found in string"))
+        );
+        assert_eq!(
+            crate::part1_with_regex("forward 5\ndown"),
+            Err(puzzle::PuzzleError::parse(2, 1, "down", "unexpected line"))
+        );
     }
 }
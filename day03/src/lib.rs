@@ -1,3 +1,5 @@
+use puzzle::PuzzleError;
+
 /// --- Day 3: Binary Diagnostic ---
 /// The submarine has been making some odd creaking noises, so you ask it to produce a diagnostic report just in case.
 ///
@@ -7,6 +9,7 @@
 ///
 /// Each bit in the gamma rate can be determined by finding the most common bit in the corresponding position of all numbers in the diagnostic report. For example, given the following diagnostic report:
 ///
+/// ```text example part1:198 part2:230
 /// 00100
 /// 11110
 /// 10110
@@ -19,6 +22,7 @@
 /// 11001
 /// 00010
 /// 01010
+/// ```
 /// Considering only the first bit of each number, there are five 0 bits and seven 1 bits. Since the most common bit is 1, the first bit of the gamma rate is 1.
 ///
 /// The most common second bit of the numbers in the diagnostic report is 0, so the second bit of the gamma rate is 0.
@@ -60,12 +64,8 @@
 /// After following these instructions, you would have a horizontal position of 15 and a depth of 10. (Multiplying these together produces 150.)
 ///
 /// Calculate the horizontal position and depth you would have after following the planned course. What do you get if you multiply your final horizontal position by your final depth?
-pub fn part1(input: &[String]) -> u32 {
-    let bit_lenght = input.first().unwrap().len() as u32;
-    let input = input
-        .iter()
-        .map(|line| u32::from_str_radix(line, 2).unwrap())
-        .collect::<Vec<u32>>();
+pub fn part1(report: &Report) -> Result<u32, PuzzleError> {
+    let (bit_lenght, input) = (report.bit_length, &report.numbers);
 
     let mut gamma_rate: u32 = 0;
     let mut epsilon_rate: u32 = 0;
@@ -83,7 +83,7 @@ pub fn part1(input: &[String]) -> u32 {
         }
     }
 
-    gamma_rate * epsilon_rate
+    Ok(gamma_rate * epsilon_rate)
 }
 
 /// --- Part Two ---
@@ -115,17 +115,17 @@ pub fn part1(input: &[String]) -> u32 {
 /// Finally, to find the life support rating, multiply the oxygen generator rating (23) by the CO2 scrubber rating (10) to get 230.
 ///
 /// Use the binary numbers in your diagnostic report to calculate the oxygen generator rating and CO2 scrubber rating, then multiply them together. What is the life support rating of the submarine? (Be sure to represent your answer in decimal, not binary.)
-pub fn part2(input: &[String]) -> u32 {
-    let bit_lenght = input.first().unwrap().len() as u32;
-
-    let input = input
-        .iter()
-        .map(|line| u32::from_str_radix(line, 2).unwrap())
-        .collect::<Vec<u32>>();
+pub fn part2(report: &Report) -> Result<u32, PuzzleError> {
+    let (bit_lenght, input) = (report.bit_length, &report.numbers);
 
     let mut bit_pos = bit_lenght;
     let mut input_filtered_for_oxygen = input.to_vec();
     while input_filtered_for_oxygen.len() != 1 {
+        if bit_pos == 0 {
+            return Err(PuzzleError::no_solution(
+                "more than one number matches the oxygen bit criteria",
+            ));
+        }
         bit_pos -= 1;
 
         let ones_count = input_filtered_for_oxygen
@@ -147,6 +147,11 @@ pub fn part2(input: &[String]) -> u32 {
     bit_pos = bit_lenght;
     let mut input_filtered_for_co2 = input.to_vec();
     while input_filtered_for_co2.len() != 1 {
+        if bit_pos == 0 {
+            return Err(PuzzleError::no_solution(
+                "more than one number matches the CO2 bit criteria",
+            ));
+        }
         bit_pos -= 1;
 
         let ones_count = input_filtered_for_co2
@@ -154,6 +159,10 @@ pub fn part2(input: &[String]) -> u32 {
             .map(|input| (*input >> bit_pos) & 1)
             .filter(|bit| *bit == 1)
             .count();
+        // When every number left has the same bit, none of them has the least common value: keep them all
+        if ones_count == 0 || ones_count == input_filtered_for_co2.len() {
+            continue;
+        }
 
         let least_common_bit = if ones_count as f32 >= (input_filtered_for_co2.len() as f32) / 2.0 {
             0
@@ -165,32 +174,92 @@ pub fn part2(input: &[String]) -> u32 {
     }
     let co2_scrubber_rating = input_filtered_for_co2.first().unwrap();
 
-    oxygen_generator_rating * co2_scrubber_rating
+    Ok(oxygen_generator_rating * co2_scrubber_rating)
+}
+
+/// The diagnostic report, its binary numbers all as wide as the first one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub bit_length: u32,
+    pub numbers: Vec<u32>,
+}
+
+/// One binary number per line.
+pub fn parse(input: &str) -> Result<Report, PuzzleError> {
+    let bit_length = input
+        .lines()
+        .next()
+        .ok_or_else(|| PuzzleError::no_solution("the diagnostic report is empty"))?
+        .len() as u32;
+    let numbers = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            u32::from_str_radix(line, 2).map_err(|error| PuzzleError::parse(i + 1, 1, line, error.to_string()))
+        })
+        .collect::<Result<Vec<u32>, PuzzleError>>()?;
+    Ok(Report { bit_length, numbers })
+}
+
+pub struct Day03;
+
+impl puzzle::Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Report;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     #[test]
     fn test_part1() {
-        let sample_input: Vec<String> = vec![
-            "00100", "11110", "10110", "10111", "10101", "01111", "00111", "11100", "10000", "11001", "00010", "01010",
-        ]
-        .iter()
-        .map(|line| line.to_string())
-        .collect();
+        let sample_input = "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010";
         let sample_output = 198;
-        assert_eq!(crate::part1(&sample_input), sample_output);
+        assert_eq!(crate::part1(&crate::parse(sample_input).unwrap()), Ok(sample_output));
     }
 
     #[test]
     fn test_part2() {
-        let sample_input: Vec<String> = vec![
-            "00100", "11110", "10110", "10111", "10101", "01111", "00111", "11100", "10000", "11001", "00010", "01010",
-        ]
-        .iter()
-        .map(|line| line.to_string())
-        .collect();
+        let sample_input = "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010";
         let sample_output = 230;
-        assert_eq!(crate::part2(&sample_input), sample_output);
+        assert_eq!(crate::part2(&crate::parse(sample_input).unwrap()), Ok(sample_output));
+    }
+
+    #[test]
+    fn test_part2_with_a_shared_bit() {
+        let sample_input = "100\n111\n110";
+        assert_eq!(crate::part2(&crate::parse(sample_input).unwrap()), Ok(7 * 4));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            crate::parse("00100\n11120"),
+            Err(puzzle::PuzzleError::parse(
+                2,
+                1,
+                "11120",
+                "invalid digit found in string"
+            ))
+        );
+
+        assert!(matches!(
+            crate::part2(&crate::parse("101\n101").unwrap()),
+            Err(puzzle::PuzzleError::NoSolution { .. })
+        ));
     }
 }
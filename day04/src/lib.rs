@@ -1,4 +1,5 @@
-use array2d::Array2D;
+use grid::Grid;
+use puzzle::PuzzleError;
 
 /// --- Day 4: Giant Squid ---
 /// You're already almost 1.5km (almost a mile) below the surface of the ocean, already so deep that you can't see any sunlight. What you can see, however, is a giant squid that has attached itself to the outside of your submarine.
@@ -9,16 +10,17 @@ use array2d::Array2D;
 ///
 /// The submarine has a bingo subsystem to help passengers (currently, you and the giant squid) pass the time. It automatically generates a random order in which to draw numbers and a random set of boards (your puzzle input). For example:
 ///
+/// ```text example part1:4512 part2:1924
 /// 7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
 ///
 /// 22 13 17 11  0
-/// 8  2 23  4 24
+///  8  2 23  4 24
 /// 21  9 14 16  7
-/// 6 10  3 18  5
-/// 1 12 20 15 19
+///  6 10  3 18  5
+///  1 12 20 15 19
 ///
-/// 3 15  0  2 22
-/// 9 18 13 17  5
+///  3 15  0  2 22
+///  9 18 13 17  5
 /// 19  8  7 25 23
 /// 20 11 10 24  4
 /// 14 21 16 12  6
@@ -27,7 +29,8 @@ use array2d::Array2D;
 /// 10 16 15  9 19
 /// 18  8 23 26 20
 /// 22 11 13  6  5
-/// 2  0 12  3  7
+///  2  0 12  3  7
+/// ```
 /// After the first five numbers are drawn (7, 4, 9, 5, and 11), there are no winners, but the boards are marked as follows (shown here adjacent to each other to save space):
 ///
 /// 22 13 17 11  0         3 15  0  2 22        14 21 17 24  4
@@ -83,17 +86,17 @@ use array2d::Array2D;
 /// After following these instructions, you would have a horizontal position of 15 and a depth of 10. (Multiplying these together produces 150.)
 ///
 /// Calculate the horizontal position and depth you would have after following the planned course. What do you get if you multiply your final horizontal position by your final depth?
-pub fn part1(instructions: &[String]) -> u32 {
-    let (numbers, mut boards) = parse_game(instructions);
+pub fn part1(game: &Game) -> Result<u32, PuzzleError> {
+    let mut boards = game.boards.clone();
 
-    for number in numbers {
+    for &number in &game.numbers {
         for board in &mut boards {
             if scratch_number_and_call(number, board) {
-                return number * count_non_scratched(board);
+                return Ok(number * count_non_scratched(board));
             }
         }
     }
-    panic!("No winner")
+    Err(PuzzleError::no_solution("No winner"))
 }
 
 /// --- Part Two ---
@@ -104,10 +107,10 @@ pub fn part1(instructions: &[String]) -> u32 {
 /// In the above example, the second board is the last to win, which happens after 13 is eventually called and its middle column is completely marked. If you were to keep playing until this point, the second board would have a sum of unmarked numbers equal to 148 for a final score of 148 * 13 = 1924.
 ///
 /// Figure out which board will win last. Once it wins, what would its final score be?
-pub fn part2(instructions: &[String]) -> u32 {
-    let (numbers, mut boards) = parse_game(instructions);
+pub fn part2(game: &Game) -> Result<u32, PuzzleError> {
+    let mut boards = game.boards.clone();
     let mut board_has_won = vec![false; boards.len()];
-    for number in numbers {
+    for &number in &game.numbers {
         for (i, board) in boards.iter_mut().enumerate() {
             if board_has_won[i] {
                 continue;
@@ -115,56 +118,106 @@ pub fn part2(instructions: &[String]) -> u32 {
             if scratch_number_and_call(number, board) {
                 board_has_won[i] = true;
                 if !board_has_won.contains(&false) {
-                    return number * count_non_scratched(board);
+                    return Ok(number * count_non_scratched(board));
                 }
             }
         }
     }
 
-    panic!("No winner")
+    Err(PuzzleError::no_solution("No winner"))
 }
 
-fn parse_game(instructions: &[String]) -> (Vec<u32>, Vec<Array2D<Option<u32>>>) {
-    let lines = instructions[0]
-        .split(',')
-        .map(|number| number.parse::<u32>().unwrap())
-        .collect();
-
-    let board = instructions[2..instructions.len()]
-        .split(|line| line.is_empty())
-        .map(|board_lines| {
-            board_lines
-                .iter()
-                .map(|board_line| {
+/// A bingo board, its numbers replaced by `None` once drawn.
+pub type Board = Grid<Option<u32>>;
+
+/// The numbers to draw, in order, and the boards before any is drawn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub numbers: Vec<u32>,
+    pub boards: Vec<Board>,
+}
+
+/// The numbers to draw separated by commas, then each board after a blank line.
+pub fn parse(input: &str) -> Result<Game, PuzzleError> {
+    let sections = inpututils::sections(input.lines());
+    let (numbers_section, board_sections) = sections
+        .split_first()
+        .ok_or_else(|| PuzzleError::parse(1, 1, "", "missing the numbers to draw"))?;
+    if let Some((line_number, line)) = numbers_section.numbered_lines().nth(1) {
+        return Err(PuzzleError::parse(
+            line_number,
+            1,
+            line,
+            "expected a blank line before the boards",
+        ));
+    }
+    let numbers = inpututils::parse_separated::<u32>(numbers_section.first_line, numbers_section.lines[0], ',')?;
+
+    let boards = board_sections
+        .iter()
+        .map(|board_section| {
+            let rows = board_section
+                .numbered_lines()
+                .map(|(line_number, board_line)| {
                     board_line
                         .split_whitespace()
-                        .map(|number| number.parse::<u32>().unwrap())
-                        .map(|number| Some(number))
-                        .collect::<Vec<Option<u32>>>()
+                        .map(|number| puzzle::parse_token::<u32>(line_number, board_line, number))
+                        .map(|number| number.map(Some))
+                        .collect::<Result<Vec<Option<u32>>, PuzzleError>>()
                 })
-                .collect::<Vec<Vec<Option<u32>>>>()
+                .collect::<Result<Vec<Vec<Option<u32>>>, PuzzleError>>()?;
+            if let Some(row) = rows.iter().position(|row| row.len() != rows[0].len()) {
+                let reason = format!("expected a row of {} numbers", rows[0].len());
+                let board_line = board_section.lines[row];
+                return Err(PuzzleError::parse(
+                    board_section.first_line + row,
+                    1,
+                    board_line,
+                    reason,
+                ));
+            }
+            Ok(Grid::from_rows(rows))
         })
-        .map(|board| Array2D::<Option<u32>>::from_rows(&board))
-        .collect();
+        .collect::<Result<Vec<_>, PuzzleError>>()?;
 
-    return (lines, board);
+    return Ok(Game { numbers, boards });
 }
 
-fn scratch_number_and_call(number: u32, board: &mut Array2D<Option<u32>>) -> bool {
-    for x in 0..board.num_columns() {
-        for y in 0..board.num_rows() {
-            if board[(y, x)] == Some(number) {
-                board[(y, x)] = None;
-                return board.column_iter(x).all(|number| number.is_none())
-                    || board.row_iter(y).all(|number| number.is_none());
-            }
+fn scratch_number_and_call(number: u32, board: &mut Board) -> bool {
+    for point in board.points() {
+        if board[point] == Some(number) {
+            board[point] = None;
+            return board.column(point.x).all(|number| number.is_none())
+                || board.row(point.y).iter().all(|number| number.is_none());
         }
     }
     return false;
 }
 
-fn count_non_scratched(board: &Array2D<Option<u32>>) -> u32 {
-    board.elements_column_major_iter().filter_map(|number| *number).sum()
+fn count_non_scratched(board: &Board) -> u32 {
+    board.iter().filter_map(|number| *number).sum()
+}
+
+pub struct Day04;
+
+impl puzzle::Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Game;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
@@ -190,9 +243,8 @@ mod tests {
 18  8 23 26 20
 22 11 13  6  5
  2  0 12  3  7";
-        let sample_input: Vec<String> = input.lines().map(|line| line.to_string()).collect();
         let sample_output = 4512;
-        assert_eq!(crate::part1(&sample_input), sample_output);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(sample_output));
     }
 
     #[test]
@@ -216,8 +268,42 @@ mod tests {
 18  8 23 26 20
 22 11 13  6  5
  2  0 12  3  7";
-        let sample_input: Vec<String> = input.lines().map(|line| line.to_string()).collect();
         let sample_output = 1924;
-        assert_eq!(crate::part2(&sample_input), sample_output);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(sample_output));
+    }
+
+    #[test]
+    fn test_errors() {
+        let input = "7,4,9
+
+22 13
+ 8  2 23";
+        assert_eq!(
+            crate::parse(input),
+            Err(puzzle::PuzzleError::parse(
+                4,
+                1,
+                " 8  2 23",
+                "expected a row of 2 numbers"
+            ))
+        );
+
+        let input = "7,4,x9
+
+22 13
+ 8  2";
+        assert_eq!(
+            crate::parse(input),
+            Err(puzzle::PuzzleError::parse(1, 5, "x9", "invalid digit found in string"))
+        );
+
+        let input = "7,4
+
+22 13
+ 8  2";
+        assert_eq!(
+            crate::part2(&crate::parse(input).unwrap()),
+            Err(puzzle::PuzzleError::no_solution("No winner"))
+        );
     }
 }
@@ -1,15 +1,18 @@
+use puzzle::PuzzleError;
 use std::cmp::max;
 use std::collections::HashMap;
 
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
-struct Point {
-    x: i32,
-    y: i32,
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
 }
 
-struct Segment {
-    i: Point,
-    f: Point,
+/// A line of vents, from its initial point to its final one.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Segment {
+    pub i: Point,
+    pub f: Point,
 }
 
 /// --- Day 5: Hydrothermal Venture ---
@@ -17,6 +20,7 @@ struct Segment {
 ///
 /// They tend to form in lines; the submarine helpfully produces a list of nearby lines of vents (your puzzle input) for you to review. For example:
 ///
+/// ```text example part1:5 part2:12
 /// 0,9 -> 5,9
 /// 8,0 -> 0,8
 /// 9,4 -> 3,4
@@ -27,6 +31,7 @@ struct Segment {
 /// 3,4 -> 1,4
 /// 0,0 -> 8,8
 /// 5,5 -> 8,2
+/// ```
 /// Each line of vents is given as a line segment in the format x1,y1 -> x2,y2 where x1,y1 are the coordinates of one end the line segment and x2,y2 are the coordinates of the other end. These line segments include the points at both ends. In other words:
 ///
 /// An entry like 1,1 -> 1,3 covers points 1,1, 1,2, and 1,3.
@@ -50,9 +55,8 @@ struct Segment {
 /// To avoid the most dangerous areas, you need to determine the number of points where at least two lines overlap. In the above example, this is anywhere in the diagram with a 2 or larger - a total of 5 points.
 ///
 /// Consider only horizontal and vertical lines. At how many points do at least two lines overlap?
-pub fn part1(instructions: &[String]) -> usize {
-    let segments = parse_segments(instructions);
-    count_dangerous_areas(&segments, false)
+pub fn part1(segments: &[Segment]) -> Result<usize, PuzzleError> {
+    Ok(count_dangerous_areas(segments, false))
 }
 
 /// --- Part Two ---
@@ -77,12 +81,11 @@ pub fn part1(instructions: &[String]) -> usize {
 /// You still need to determine the number of points where at least two lines overlap. In the above example, this is still anywhere in the diagram with a 2 or larger - now a total of 12 points.
 ///
 /// Consider all of the lines. At how many points do at least two lines overlap?
-pub fn part2(instructions: &[String]) -> usize {
-    let segments = parse_segments(instructions);
-    count_dangerous_areas(&segments, true)
+pub fn part2(segments: &[Segment]) -> Result<usize, PuzzleError> {
+    Ok(count_dangerous_areas(segments, true))
 }
 
-fn count_dangerous_areas(segments: &Vec<Segment>, count_diagonally: bool) -> usize {
+fn count_dangerous_areas(segments: &[Segment], count_diagonally: bool) -> usize {
     let mut memory = HashMap::<Point, i32>::new();
 
     for segment in segments {
@@ -105,29 +108,50 @@ fn count_dangerous_areas(segments: &Vec<Segment>, count_diagonally: bool) -> usi
     memory.iter().filter(|&(_, count)| *count >= 2).count()
 }
 
-fn parse_segments(instructions: &[String]) -> Vec<Segment> {
-    instructions
-        .iter()
-        .map(|line| {
+/// One segment per line, like `0,9 -> 5,9`.
+pub fn parse(input: &str) -> Result<Vec<Segment>, PuzzleError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
             let points = line
                 .split(" -> ")
                 .map(|coordinate| {
-                    let coordinates = coordinate
-                        .split(',')
-                        .map(|part| part.parse::<i32>().unwrap())
-                        .collect::<Vec<i32>>();
-                    Point {
-                        x: coordinates[0],
-                        y: coordinates[1],
-                    }
+                    let (x, y) = inpututils::coordinate::<i32>(i + 1, line, coordinate)?;
+                    Ok(Point { x, y })
                 })
-                .collect::<Vec<Point>>();
-            Segment {
+                .collect::<Result<Vec<Point>, PuzzleError>>()?;
+            if points.len() != 2 {
+                return Err(PuzzleError::parse(i + 1, 1, line, "expected `x1,y1 -> x2,y2`"));
+            }
+            Ok(Segment {
                 i: points[0],
                 f: points[1],
-            }
+            })
         })
-        .collect::<Vec<Segment>>()
+        .collect::<Result<Vec<Segment>, PuzzleError>>()
+}
+
+pub struct Day05;
+
+impl puzzle::Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Vec<Segment>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
@@ -144,9 +168,8 @@ mod tests {
 3,4 -> 1,4
 0,0 -> 8,8
 5,5 -> 8,2";
-        let sample_input: Vec<String> = input.lines().map(|line| line.to_string()).collect();
         let sample_output = 5;
-        assert_eq!(crate::part1(&sample_input), sample_output);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(sample_output));
     }
 
     #[test]
@@ -161,8 +184,25 @@ mod tests {
 3,4 -> 1,4
 0,0 -> 8,8
 5,5 -> 8,2";
-        let sample_input: Vec<String> = input.lines().map(|line| line.to_string()).collect();
         let sample_output = 12;
-        assert_eq!(crate::part2(&sample_input), sample_output);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(sample_output));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            crate::parse("0,9 -> 5,9\n8,0 -> 0,8,1"),
+            Err(puzzle::PuzzleError::parse(2, 8, "0,8,1", "expected `x,y`"))
+        );
+
+        assert_eq!(
+            crate::parse("0,9 -> 5,9 -> 1,1"),
+            Err(puzzle::PuzzleError::parse(
+                1,
+                1,
+                "0,9 -> 5,9 -> 1,1",
+                "expected `x1,y1 -> x2,y2`"
+            ))
+        );
     }
 }
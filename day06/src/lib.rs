@@ -1,3 +1,5 @@
+use puzzle::PuzzleError;
+
 /// --- Day 6: Lanternfish ---
 /// The sea floor is getting steeper. Maybe the sleigh keys got carried this way?
 ///
@@ -20,7 +22,9 @@
 ///
 /// Realizing what you're trying to do, the submarine automatically produces a list of the ages of several hundred nearby lanternfish (your puzzle input). For example, suppose you were given the following list:
 ///
+/// ```text example part1:5934 part2:26984457539
 /// 3,4,3,1,2
+/// ```
 /// This list means that the first fish has an internal timer of 3, the second fish has an internal timer of 4, and so on until the fifth fish, which has an internal timer of 2. Simulating these fish over several days would proceed as follows:
 ///
 /// Initial state: 3,4,3,1,2
@@ -47,8 +51,8 @@
 /// In this example, after 18 days, there are a total of 26 fish. After 80 days, there would be a total of 5934.
 ///
 /// Find a way to simulate lanternfish. How many lanternfish would there be after 80 days?
-pub fn part1(instructions: &[u8]) -> usize {
-    return reproduce(instructions, 80);
+pub fn part1(instructions: &[u8]) -> Result<usize, PuzzleError> {
+    Ok(reproduce(instructions, 80))
 }
 
 /// --- Part Two ---
@@ -57,8 +61,8 @@ pub fn part1(instructions: &[u8]) -> usize {
 /// After 256 days in the example above, there would be a total of 26984457539 lanternfish!
 ///
 /// How many lanternfish would there be after 256 days?
-pub fn part2(instructions: &[u8]) -> usize {
-    return reproduce(instructions, 256);
+pub fn part2(instructions: &[u8]) -> Result<usize, PuzzleError> {
+    Ok(reproduce(instructions, 256))
 }
 
 fn reproduce(vec: &[u8], days: u32) -> usize {
@@ -81,6 +85,42 @@ fn reproduce(vec: &[u8], days: u32) -> usize {
     histogram.iter().sum()
 }
 
+pub fn parse(input: &str) -> Result<Vec<u8>, PuzzleError> {
+    input
+        .split(',')
+        .map(|number| {
+            let days_to_reproduce = puzzle::parse_token::<u8>(1, input, number)?;
+            if days_to_reproduce > 8 {
+                let column = puzzle::column_of(input, number);
+                return Err(PuzzleError::parse(1, column, number, "timers go from 0 to 8"));
+            }
+            Ok(days_to_reproduce)
+        })
+        .collect()
+}
+
+pub struct Day06;
+
+impl puzzle::Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -91,7 +131,7 @@ mod tests {
             .map(|number| number.parse::<u8>().unwrap())
             .collect::<Vec<u8>>();
         let sample_output = 5934;
-        assert_eq!(crate::part1(&sample_input), sample_output);
+        assert_eq!(crate::part1(&sample_input), Ok(sample_output));
     }
 
     #[test]
@@ -102,6 +142,24 @@ mod tests {
             .map(|number| number.parse::<u8>().unwrap())
             .collect::<Vec<u8>>();
         let sample_output = 26984457539;
-        assert_eq!(crate::part2(&sample_input), sample_output);
+        assert_eq!(crate::part2(&sample_input), Ok(sample_output));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(crate::parse("3,4,3,1,2"), Ok(vec![3, 4, 3, 1, 2]));
+        assert_eq!(
+            crate::parse("3,4,9,1,2"),
+            Err(puzzle::PuzzleError::parse(1, 5, "9", "timers go from 0 to 8"))
+        );
+        assert_eq!(
+            crate::parse("3,4,,1,2"),
+            Err(puzzle::PuzzleError::parse(
+                1,
+                5,
+                "",
+                "cannot parse integer from empty string"
+            ))
+        );
     }
 }
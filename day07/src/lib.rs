@@ -1,3 +1,5 @@
+use puzzle::PuzzleError;
+
 /// --- Day 7: The Treachery of Whales ---
 /// A giant whale has decided your submarine is its next meal, and it's much faster than you are. There's nowhere to run!
 ///
@@ -11,7 +13,9 @@
 ///
 /// For example, consider the following horizontal positions:
 ///
+/// ```text example part1:37 part2:168
 /// 16,1,2,0,4,2,7,1,2,14
+/// ```
 /// This means there's a crab with horizontal position 16, a crab with horizontal position 1, and so on.
 ///
 /// Each change of 1 step in horizontal position of a single crab costs 1 fuel. You could choose any horizontal position to align them all on, but the one that costs the least fuel is horizontal position 2:
@@ -29,20 +33,20 @@
 /// This costs a total of 37 fuel. This is the cheapest possible outcome; more expensive outcomes include aligning at position 1 (41 fuel), position 3 (39 fuel), or position 10 (71 fuel).
 ///
 /// Determine the horizontal position that the crabs can align to using the least fuel possible. How much fuel must they spend to align to that position?
-pub fn part1(crabs: &mut [u64]) -> u64 {
+pub fn part1(crabs: &mut [u64]) -> Result<u64, PuzzleError> {
     // Note: select_nth_unstable is a faster alternative to find the nth sorted element.
     // It doesn't sort the whole array, just enought to grant the solicited index & is optimized for it.
     let median = *crabs.select_nth_unstable(crabs.len() / 2).1;
-    return crabs.iter().map(|crab| abs_diff(*crab, median)).sum::<u64>();
+    Ok(crabs.iter().map(|crab| abs_diff(*crab, median)).sum::<u64>())
 }
 
-pub fn part1_naive(crabs: &[u64]) -> u64 {
+pub fn part1_naive(crabs: &[u64]) -> Result<u64, PuzzleError> {
     let min = crabs.iter().min().unwrap();
     let max = crabs.iter().max().unwrap();
-    return (*min..=*max)
+    Ok((*min..=*max)
         .map(|position| crabs.iter().map(|val| abs_diff(position, *val)).sum::<u64>())
         .min()
-        .unwrap();
+        .unwrap())
 }
 
 /// --- Part Two ---
@@ -65,12 +69,13 @@ pub fn part1_naive(crabs: &[u64]) -> u64 {
 /// This costs a total of 168 fuel. This is the new cheapest possible outcome; the old alignment position (2) now costs 206 fuel instead.
 ///
 /// Determine the horizontal position that the crabs can align to using the least fuel possible so they can make you an escape route! How much fuel must they spend to align to that position?
-pub fn part2(instructions: &[u64]) -> u64 {
+pub fn part2(instructions: &[u64]) -> Result<u64, PuzzleError> {
     // The fuel function to minimize is (n^2+n)/2.
     // Its 1st derivative is (2n+1)/2 or n + 1/2
-    // So it must be close to the average, but might be a bit off
+    // So the best real position is within 1/2 of the average, and the best integer one is at most 1 away from the
+    // rounded down average, either way.
     let average = instructions.iter().sum::<u64>() / instructions.len() as u64;
-    return (average - 1..average + 1)
+    Ok((average.saturating_sub(1)..=average + 1)
         .map(|position| {
             instructions
                 .iter()
@@ -78,13 +83,13 @@ pub fn part2(instructions: &[u64]) -> u64 {
                 .sum::<u64>()
         })
         .min()
-        .unwrap();
+        .unwrap())
 }
 
-pub fn part2_naive(instructions: &[u64]) -> u64 {
+pub fn part2_naive(instructions: &[u64]) -> Result<u64, PuzzleError> {
     let min = instructions.iter().min().unwrap();
     let max = instructions.iter().max().unwrap();
-    return (*min..=*max)
+    Ok((*min..=*max)
         .map(|position| {
             instructions
                 .iter()
@@ -92,7 +97,7 @@ pub fn part2_naive(instructions: &[u64]) -> u64 {
                 .sum::<u64>()
         })
         .min()
-        .unwrap();
+        .unwrap())
 }
 
 fn abs_diff(slf: u64, other: u64) -> u64 {
@@ -111,12 +116,53 @@ fn fuel(n: u64) -> u64 {
 #[allow(dead_code)]
 fn fuel_naive(n: u64) -> u64 {
     if n < 2 {
-        1
+        n
     } else {
         n + fuel_naive(n - 1)
     }
 }
 
+pub fn parse(input: &str) -> Result<Vec<u64>, PuzzleError> {
+    inpututils::parse_comma_separated(input)
+}
+
+pub struct Day07;
+
+impl puzzle::Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(&mut input.to_vec())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+
+    fn variants() -> Vec<puzzle::Variant<Self::Input>> {
+        vec![
+            puzzle::Variant {
+                name: "part1_naive",
+                part: puzzle::Part::One,
+                solve: |input| part1_naive(input).map(puzzle::Answer::from),
+            },
+            puzzle::Variant {
+                name: "part2_naive",
+                part: puzzle::Part::Two,
+                solve: |input| part2_naive(input).map(puzzle::Answer::from),
+            },
+        ]
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -128,7 +174,7 @@ mod tests {
             .map(|number| number.parse::<u64>().unwrap())
             .collect::<Vec<u64>>();
         let sample_output = 37;
-        assert_eq!(crate::part1(&mut sample_input), sample_output);
+        assert_eq!(crate::part1(&mut sample_input), Ok(sample_output));
     }
 
     #[test]
@@ -139,7 +185,7 @@ mod tests {
             .map(|number| number.parse::<u64>().unwrap())
             .collect::<Vec<u64>>();
         let sample_output = 37;
-        assert_eq!(crate::part1_naive(&sample_input), sample_output);
+        assert_eq!(crate::part1_naive(&sample_input), Ok(sample_output));
     }
 
     #[test]
@@ -150,7 +196,7 @@ mod tests {
             .map(|number| number.parse::<u64>().unwrap())
             .collect::<Vec<u64>>();
         let sample_output = 168;
-        assert_eq!(crate::part2(&sample_input), sample_output);
+        assert_eq!(crate::part2(&sample_input), Ok(sample_output));
     }
 
     #[test]
@@ -161,7 +207,7 @@ mod tests {
             .map(|number| number.parse::<u64>().unwrap())
             .collect::<Vec<u64>>();
         let sample_output = 168;
-        assert_eq!(crate::part2_naive(&sample_input), sample_output);
+        assert_eq!(crate::part2_naive(&sample_input), Ok(sample_output));
     }
 
     #[test]
@@ -179,4 +225,20 @@ mod tests {
         assert_eq!(crate::fuel_naive(3), 3 + 2 + 1);
         assert_eq!(crate::fuel_naive(4), 4 + 3 + 2 + 1);
     }
+
+    #[test]
+    fn test_fuel_agrees_with_fuel_naive() {
+        for n in 0..500 {
+            assert_eq!(crate::fuel(n), crate::fuel_naive(n), "{} steps", n);
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(crate::parse("16,1,2"), Ok(vec![16, 1, 2]));
+        assert_eq!(
+            crate::parse("16,-1,2"),
+            Err(puzzle::PuzzleError::parse(1, 4, "-1", "invalid digit found in string"))
+        );
+    }
 }
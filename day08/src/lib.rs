@@ -1,3 +1,4 @@
+use puzzle::PuzzleError;
 use std::collections::HashSet;
 
 /// --- Day 8: Seven Segment Search ---
@@ -44,53 +45,111 @@ use std::collections::HashSet;
 ///
 /// For now, focus on the easy digits. Consider this larger example:
 ///
-/// be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb |
-/// fdgacbe cefdb cefbgd gcbe
-/// edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec |
-/// fcgedb cgb dgebacf gc
-/// fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef |
-/// cg cg fdcagb cbg
-/// fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega |
-/// efabcd cedba gadfec cb
-/// aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga |
-/// gecf egdcabf bgf bfgea
-/// fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf |
-/// gebdcfa ecba ca fadegcb
-/// dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf |
-/// cefg dcbef fcge gbcadfe
-/// bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd |
-/// ed bcgafe cdgba cbgef
-/// egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg |
-/// gbdfcae bgc cg cgb
-/// gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc |
-/// fgae cfgab fg bagce
+/// ```text example part1:26 part2:61229
+/// be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+/// edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+/// fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+/// fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+/// aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+/// fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+/// dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+/// bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+/// egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+/// gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
+/// ```
 /// Because the digits 1, 4, 7, and 8 each use a unique number of segments, you should be able to tell which combinations of signals correspond to those digits. Counting only digits in the output values (the part after | on each line), in the above example, there are 26 instances of digits that use a unique number of segments (highlighted above).
 ///
 /// In the output values, how many times do digits 1, 4, 7, or 8 appear?
-pub fn part1(input: &str) -> usize {
+pub fn part1(entries: &[Entry]) -> Result<usize, PuzzleError> {
+    entries
+        .iter()
+        .enumerate()
+        .map(
+            |(
+                i,
+                Entry {
+                    patterns: inputs,
+                    outputs,
+                },
+            )| {
+                let digit_1 = inputs
+                    .iter()
+                    .find(|input| input.len() == 2)
+                    .ok_or_else(|| unknown_wiring(i + 1))?;
+                let digit_4 = inputs
+                    .iter()
+                    .find(|input| input.len() == 4)
+                    .ok_or_else(|| unknown_wiring(i + 1))?;
+                let digit_7 = inputs
+                    .iter()
+                    .find(|input| input.len() == 3)
+                    .ok_or_else(|| unknown_wiring(i + 1))?;
+                let digit_8 = inputs
+                    .iter()
+                    .find(|input| input.len() == 7)
+                    .ok_or_else(|| unknown_wiring(i + 1))?;
+
+                return Ok(outputs
+                    .iter()
+                    .filter(|&output| {
+                        *output == *digit_1 || *output == *digit_4 || *output == *digit_7 || *output == *digit_8
+                    })
+                    .count());
+            },
+        )
+        .sum()
+}
+
+/// The segments lit for each digit shown.
+type Patterns = Vec<HashSet<char>>;
+
+/// The signal patterns of the ten digits, and the digits of the output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub patterns: Patterns,
+    pub outputs: Patterns,
+}
+
+/// An entry per line, like `<patterns> | <output>`.
+pub fn parse(input: &str) -> Result<Vec<Entry>, PuzzleError> {
     input
         .lines()
-        .map(|instructions| {
-            let parts = instructions.split(" | ").collect::<Vec<&str>>();
-            let inputs = parse_digits_signals(parts[0]);
-            let outputs = parse_digits_signals(parts[1]);
+        .enumerate()
+        .map(|(i, entry)| parse_entry(i + 1, entry))
+        .collect()
+}
 
-            let digit_1 = inputs.iter().find(|input| input.len() == 2).unwrap();
-            let digit_4 = inputs.iter().find(|input| input.len() == 4).unwrap();
-            let digit_7 = inputs.iter().find(|input| input.len() == 3).unwrap();
-            let digit_8 = inputs.iter().find(|input| input.len() == 7).unwrap();
+fn parse_entry(line_number: usize, entry: &str) -> Result<Entry, PuzzleError> {
+    let (inputs, outputs) = entry
+        .split_once(" | ")
+        .ok_or_else(|| PuzzleError::parse(line_number, 1, entry, "expected `<patterns> | <output>`"))?;
+    let patterns = parse_digits_signals(inputs);
+    // The digits with a number of segments of their own, 1, 7, 4 and 8, are where deducing the wiring starts from
+    if [2, 3, 4, 7]
+        .iter()
+        .any(|&segments| !patterns.iter().any(|pattern| pattern.len() == segments))
+    {
+        return Err(PuzzleError::parse(
+            line_number,
+            1,
+            entry,
+            "cannot deduce the wiring from the signal patterns",
+        ));
+    }
+    Ok(Entry {
+        patterns,
+        outputs: parse_digits_signals(outputs),
+    })
+}
 
-            return outputs
-                .iter()
-                .filter(|&output| {
-                    *output == *digit_1 || *output == *digit_4 || *output == *digit_7 || *output == *digit_8
-                })
-                .count();
-        })
-        .sum()
+fn unknown_wiring(entry_number: usize) -> PuzzleError {
+    PuzzleError::no_solution(format!(
+        "cannot deduce the wiring from the signal patterns of entry {}",
+        entry_number
+    ))
 }
 
-fn parse_digits_signals(string: &str) -> Vec<HashSet<char>> {
+fn parse_digits_signals(string: &str) -> Patterns {
     string
         .split_whitespace()
         .map(|digit| digit.chars().collect::<HashSet<char>>())
@@ -146,25 +205,36 @@ fn parse_digits_signals(string: &str) -> Vec<HashSet<char>> {
 /// Adding all of the output values in this larger example produces 61229.
 ///
 /// For each entry, determine all of the wire/segment connections and decode the four-digit output values. What do you get if you add up all of the output values?
-pub fn part2(input: &str) -> usize {
-    input
-        .lines()
-        .map(|instructions| {
-            let parts = instructions.split(" | ").collect::<Vec<&str>>();
-            let mut inputs = parse_digits_signals(parts[0]);
-            let outputs = parse_digits_signals(parts[1]);
+pub fn part2(entries: &[Entry]) -> Result<usize, PuzzleError> {
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, Entry { patterns, outputs })| {
+            let mut inputs = patterns.clone();
 
             // - Digits with unique number of segments: 1, 4, 7, 8
-            let digit_1_pos = inputs.iter().position(|input| input.len() == 2).unwrap();
+            let digit_1_pos = inputs
+                .iter()
+                .position(|input| input.len() == 2)
+                .ok_or_else(|| unknown_wiring(i + 1))?;
             let digit_1 = inputs.remove(digit_1_pos);
 
-            let digit_4_pos = inputs.iter().position(|input| input.len() == 4).unwrap();
+            let digit_4_pos = inputs
+                .iter()
+                .position(|input| input.len() == 4)
+                .ok_or_else(|| unknown_wiring(i + 1))?;
             let digit_4 = inputs.remove(digit_4_pos);
 
-            let digit_7_pos = inputs.iter().position(|input| input.len() == 3).unwrap();
+            let digit_7_pos = inputs
+                .iter()
+                .position(|input| input.len() == 3)
+                .ok_or_else(|| unknown_wiring(i + 1))?;
             let digit_7 = inputs.remove(digit_7_pos);
 
-            let digit_8_pos = inputs.iter().position(|input| input.len() == 7).unwrap();
+            let digit_8_pos = inputs
+                .iter()
+                .position(|input| input.len() == 7)
+                .ok_or_else(|| unknown_wiring(i + 1))?;
             let digit_8 = inputs.remove(digit_8_pos);
 
             // - Digits with 6 segments: 0, 6, 9
@@ -173,18 +243,21 @@ pub fn part2(input: &str) -> usize {
             let digit_6_pos = inputs
                 .iter()
                 .position(|input| input.len() == 6 && input.intersection(&digit_1).count() == 1)
-                .unwrap();
+                .ok_or_else(|| unknown_wiring(i + 1))?;
             let digit_6 = inputs.remove(digit_6_pos);
 
             // Digit 9 is the only 6 segment digit that contains all 4 segments of digit 4
             let digit_9_pos = inputs
                 .iter()
                 .position(|input| input.len() == 6 && input.intersection(&digit_4).count() == 4)
-                .unwrap();
+                .ok_or_else(|| unknown_wiring(i + 1))?;
             let digit_9 = inputs.remove(digit_9_pos);
 
             // Digit 0 is the only 6 segment left
-            let digit_0_pos = inputs.iter().position(|input| input.len() == 6).unwrap();
+            let digit_0_pos = inputs
+                .iter()
+                .position(|input| input.len() == 6)
+                .ok_or_else(|| unknown_wiring(i + 1))?;
             let digit_0 = inputs.remove(digit_0_pos);
 
             // - Digits with 5 segments: 2, 3, 5
@@ -193,52 +266,77 @@ pub fn part2(input: &str) -> usize {
             let digit_3_pos = inputs
                 .iter()
                 .position(|input| input.len() == 5 && input.intersection(&digit_1).count() == 2)
-                .unwrap();
+                .ok_or_else(|| unknown_wiring(i + 1))?;
             let digit_3 = inputs.remove(digit_3_pos);
 
             // Wihtout digit 3, digit 5 is the only 5 segment digit wich is fully contained withing digit 9
             let digit_5_pos = inputs
                 .iter()
                 .position(|input| input.len() == 5 && input.intersection(&digit_9).count() == 5)
-                .unwrap();
+                .ok_or_else(|| unknown_wiring(i + 1))?;
             let digit_5 = inputs.remove(digit_5_pos);
 
             // Digit 2 is the only 5 segment left
-            let digit_2_pos = inputs.iter().position(|input| input.len() == 5).unwrap();
+            let digit_2_pos = inputs
+                .iter()
+                .position(|input| input.len() == 5)
+                .ok_or_else(|| unknown_wiring(i + 1))?;
             let digit_2 = inputs.remove(digit_2_pos);
 
             outputs
                 .iter()
                 .map(|output| {
                     if *output == digit_0 {
-                        0
+                        Ok(0)
                     } else if *output == digit_1 {
-                        1
+                        Ok(1)
                     } else if *output == digit_2 {
-                        2
+                        Ok(2)
                     } else if *output == digit_3 {
-                        3
+                        Ok(3)
                     } else if *output == digit_4 {
-                        4
+                        Ok(4)
                     } else if *output == digit_5 {
-                        5
+                        Ok(5)
                     } else if *output == digit_6 {
-                        6
+                        Ok(6)
                     } else if *output == digit_7 {
-                        7
+                        Ok(7)
                     } else if *output == digit_8 {
-                        8
+                        Ok(8)
                     } else if *output == digit_9 {
-                        9
+                        Ok(9)
                     } else {
-                        panic!("Unexpected digit {:?}", output)
+                        Err(unknown_wiring(i + 1))
                     }
                 })
-                .fold(0, |acc, digit| acc * 10 + digit)
+                .try_fold(0, |acc, digit| digit.map(|digit| acc * 10 + digit))
         })
         .sum()
 }
 
+pub struct Day08;
+
+impl puzzle::Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Vec<Entry>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -253,7 +351,7 @@ dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbc
 bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
 egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
 gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
-        assert_eq!(crate::part1(input), 26);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(26));
     }
 
     #[test]
@@ -268,6 +366,32 @@ dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbc
 bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
 egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
 gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
-        assert_eq!(crate::part2(input), 61229);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(61229));
+    }
+
+    #[test]
+    fn test_errors() {
+        let input = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec fcgedb cgb dgebacf gc";
+        assert_eq!(
+            crate::parse(input),
+            Err(puzzle::PuzzleError::parse(
+                2,
+                1,
+                "edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec fcgedb cgb dgebacf gc",
+                "expected `<patterns> | <output>`"
+            ))
+        );
+
+        let input = "cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe";
+        assert_eq!(
+            crate::parse(input),
+            Err(puzzle::PuzzleError::parse(
+                1,
+                1,
+                input,
+                "cannot deduce the wiring from the signal patterns"
+            ))
+        );
     }
 }
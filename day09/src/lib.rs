@@ -1,100 +1,83 @@
-use array2d::Array2D;
+use grid::{Grid, Point};
+use puzzle::PuzzleError;
 use std::collections::HashSet;
 
-pub fn part1(input: &str) -> u32 {
-    let lines = input
-        .lines()
-        .map(|instruction| {
-            instruction
-                .chars()
-                .map(|c| c.to_digit(10).unwrap())
-                .collect::<Vec<u32>>()
-        })
-        .collect::<Vec<Vec<u32>>>();
-
-    let map = Array2D::<u32>::from_rows(&lines);
-
+pub fn part1(map: &Grid<u32>) -> Result<u32, PuzzleError> {
     let mut sum = 0;
-    let adjacent_points: Vec<(i32, i32)> = vec![(-1, 0), (1, 0), (0, -1), (0, 1)];
-    for x in 0..map.num_columns() {
-        for y in 0..map.num_rows() {
-            if adjacent_points
-                .iter()
-                .map(|adjacent_point| (x as i32 + adjacent_point.0, y as i32 + adjacent_point.1))
-                .all(|adjacent_coordinate| {
-                    let is_within = (0..map.num_columns() as i32).contains(&adjacent_coordinate.0)
-                        && (0..map.num_rows() as i32).contains(&adjacent_coordinate.1);
-                    if is_within {
-                        map[(y, x)] < map[(adjacent_coordinate.1 as usize, adjacent_coordinate.0 as usize)]
-                    } else {
-                        true
-                    }
-                })
-            {
-                sum += map[(y, x)] + 1
-            }
+    for point in map.points() {
+        if map.neighbours4(point).all(|neighbour| map[point] < map[neighbour]) {
+            sum += map[point] + 1
         }
     }
-    return sum;
+    return Ok(sum);
 }
 
-pub fn part2(input: &str) -> usize {
-    let lines = input
-        .lines()
-        .map(|instruction| {
-            instruction
-                .chars()
-                .map(|c| c.to_digit(10).unwrap())
-                .collect::<Vec<u32>>()
-        })
-        .collect::<Vec<Vec<u32>>>();
-
-    let map = Array2D::<u32>::from_rows(&lines);
-
-    let mut known_basin_points: HashSet<(usize, usize)> = HashSet::new();
-
-    fn check_for_basin(
-        x: usize,
-        y: usize,
-        map: &Array2D<u32>,
-        known_basin_points: &mut HashSet<(usize, usize)>,
-    ) -> usize {
-        let adjacent_points: Vec<(i32, i32)> = vec![(-1, 0), (1, 0), (0, -1), (0, 1)];
-        if known_basin_points.contains(&(x, y)) || map[(y, x)] == 9 {
+pub fn part2(map: &Grid<u32>) -> Result<usize, PuzzleError> {
+    let mut known_basin_points: HashSet<Point> = HashSet::new();
+
+    fn check_for_basin(point: Point, map: &Grid<u32>, known_basin_points: &mut HashSet<Point>) -> usize {
+        if known_basin_points.contains(&point) || map[point] == 9 {
             return 0;
         }
-        known_basin_points.insert((x, y));
-        return 1 + adjacent_points
-            .iter()
-            .map(|adjacent_point| (x as i32 + adjacent_point.0, y as i32 + adjacent_point.1))
-            .filter(|adjacent_coordinate| {
-                (0..map.num_columns() as i32).contains(&adjacent_coordinate.0)
-                    && (0..map.num_rows() as i32).contains(&adjacent_coordinate.1)
-            })
-            .map(|adjacent_coordinate| (adjacent_coordinate.0 as usize, adjacent_coordinate.1 as usize))
-            .map(|adjacent_coordinate| {
-                check_for_basin(adjacent_coordinate.0, adjacent_coordinate.1, map, known_basin_points)
-            })
+        known_basin_points.insert(point);
+        return 1 + map
+            .neighbours4(point)
+            .map(|neighbour| check_for_basin(neighbour, map, known_basin_points))
             .sum::<usize>();
     }
 
     let mut known_basin_sizes: Vec<usize> = Vec::new();
 
-    for x in 0..map.num_columns() {
-        for y in 0..map.num_rows() {
-            let basin_size = check_for_basin(x, y, &map, &mut known_basin_points);
-            if basin_size > 0 {
-                known_basin_sizes.push(basin_size)
-            }
+    for point in map.points() {
+        let basin_size = check_for_basin(point, map, &mut known_basin_points);
+        if basin_size > 0 {
+            known_basin_sizes.push(basin_size)
         }
     }
 
     known_basin_sizes.sort_by(|a, b| b.cmp(a));
 
-    return known_basin_sizes
+    return Ok(known_basin_sizes
         .iter()
         .take(3)
-        .fold(1, |acc, basin_size| acc * basin_size);
+        .fold(1, |acc, basin_size| acc * basin_size));
+}
+
+/// The height of every location, a digit each.
+///
+/// For example:
+///
+/// ```text example part1:15 part2:1134
+/// 2199943210
+/// 3987894921
+/// 9856789892
+/// 8767896789
+/// 9899965678
+/// ```
+pub fn parse(input: &str) -> Result<Grid<u32>, PuzzleError> {
+    grid::parse_digits(input)
+}
+
+pub struct Day09;
+
+impl puzzle::Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
@@ -106,7 +89,7 @@ mod tests {
 9856789892
 8767896789
 9899965678";
-        assert_eq!(crate::part1(input), 15);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(15));
     }
 
     #[test]
@@ -116,6 +99,23 @@ mod tests {
 9856789892
 8767896789
 9899965678";
-        assert_eq!(crate::part2(input), 1134);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(1134));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            crate::parse("2199943210\n39878949x1"),
+            Err(puzzle::PuzzleError::parse(2, 9, "x", "expected a digit"))
+        );
+        assert_eq!(
+            crate::parse("2199943210\n398789491"),
+            Err(puzzle::PuzzleError::parse(
+                2,
+                1,
+                "398789491",
+                "expected 10 characters like the first line"
+            ))
+        );
     }
 }
@@ -1,4 +1,6 @@
-pub fn part1(str: &str) -> usize {
+use puzzle::PuzzleError;
+
+pub fn part1(lines: &[String]) -> Result<usize, PuzzleError> {
     let mut score = 0;
 
     fn seek<I>(close_char: Option<char>, chars: &mut I) -> Option<usize>
@@ -53,20 +55,20 @@ pub fn part1(str: &str) -> usize {
                         return None;
                     }
                 }
-                _ => panic!("Unexpected character"),
+                _ => unreachable!("Unexpected character"),
             }
         }
     }
 
-    for line in str.lines() {
+    for line in lines {
         let mut iter = line.chars();
         score += seek(None, &mut iter).unwrap_or(0);
     }
 
-    score
+    Ok(score)
 }
 
-pub fn part2(str: &str) -> usize {
+pub fn part2(lines: &[String]) -> Result<usize, PuzzleError> {
     fn seek<I>(close_char: Option<char>, chars: &mut I) -> Option<usize>
     where
         I: Iterator<Item = char>,
@@ -81,7 +83,7 @@ pub fn part2(str: &str) -> usize {
                     Some(']') => return Some(5 * acc_score + 2),
                     Some('}') => return Some(5 * acc_score + 3),
                     Some('>') => return Some(5 * acc_score + 4),
-                    Some(_) => panic!("Unexpected character"),
+                    Some(_) => unreachable!("Unexpected character"),
                 },
                 Some('(') => match seek(Some(')'), chars) {
                     Some(score) => acc_score += score,
@@ -127,13 +129,13 @@ pub fn part2(str: &str) -> usize {
                         return Some(0);
                     }
                 }
-                _ => panic!("Unexpected character"),
+                _ => unreachable!("Unexpected character"),
             }
         }
     }
 
     let mut scores: Vec<usize> = Vec::new();
-    for instruction in str.lines() {
+    for instruction in lines {
         let mut iter = instruction.chars();
         match seek(None, &mut iter) {
             Some(score) => scores.push(score),
@@ -141,8 +143,63 @@ pub fn part2(str: &str) -> usize {
         }
     }
 
+    if scores.is_empty() {
+        return Err(PuzzleError::no_solution("there are no incomplete lines"));
+    }
+
     scores.sort();
-    scores[scores.len() / 2]
+    Ok(scores[scores.len() / 2])
+}
+
+/// The lines of chunks, made only of `()[]{}<>`.
+///
+/// For example:
+///
+/// ```text example part1:26397 part2:288957
+/// [({(<(())[]>[[{[]{<()<>>
+/// [(()[<>])]({[<{<<[]>>(
+/// {([(<{}[<>[]}>{[]{[(<()>
+/// (((({<>}<{<{<>}{[]{[]{}
+/// [[<[([]))<([[{}[[()]]]
+/// [{[{({}]{}}([{[{{{}}([]
+/// {<[[]]>}<{[{[{[]{()[[[]
+/// [<(<(<(<{}))><([]([]()
+/// <{([([[(<>()){}]>(<<{{
+/// <{([{{}}[<[[[<>{}]]]>[]]
+/// ```
+pub fn parse(input: &str) -> Result<Vec<String>, PuzzleError> {
+    input
+        .lines()
+        .enumerate()
+        .map(
+            |(i, line)| match line.chars().enumerate().find(|(_, c)| !"()[]{}<>".contains(*c)) {
+                Some((j, c)) => Err(PuzzleError::parse(i + 1, j + 1, c, "expected one of `()[]{}<>`")),
+                None => Ok(line.to_string()),
+            },
+        )
+        .collect()
+}
+
+pub struct Day10;
+
+impl puzzle::Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
@@ -159,7 +216,7 @@ mod tests {
 [<(<(<(<{}))><([]([]()
 <{([([[(<>()){}]>(<<{{
 <{([{{}}[<[[[<>{}]]]>[]]";
-        assert_eq!(crate::part1(input), 26397);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(26397));
     }
 
     #[test]
@@ -174,6 +231,18 @@ mod tests {
 [<(<(<(<{}))><([]([]()
 <{([([[(<>()){}]>(<<{{
 <{([{{}}[<[[[<>{}]]]>[]]";
-        assert_eq!(crate::part2(input), 288957);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(288957));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            crate::parse("[({(<(())[]>[[{[]{<()<>>\n[(()[<>]a)]({[<{<<[]>>("),
+            Err(puzzle::PuzzleError::parse(2, 9, "a", "expected one of `()[]{}<>`"))
+        );
+        assert_eq!(
+            crate::part2(&crate::parse("{([(<{}[<>[]}>{[]{[(<()>").unwrap()),
+            Err(puzzle::PuzzleError::no_solution("there are no incomplete lines"))
+        );
     }
 }
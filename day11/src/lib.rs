@@ -1,130 +1,115 @@
-use array2d::Array2D;
+use grid::{Grid, Point};
+use puzzle::PuzzleError;
 
-pub fn part1(input: &str) -> usize {
-    let lines = input
-        .lines()
-        .map(|instruction| {
-            instruction
-                .chars()
-                .map(|c| c.to_digit(10).unwrap())
-                .collect::<Vec<u32>>()
-        })
-        .collect::<Vec<Vec<u32>>>();
+pub fn part1(energy_levels: &Grid<u32>) -> Result<usize, PuzzleError> {
+    let mut map = energy_levels.clone();
 
-    let mut map = Array2D::<u32>::from_rows(&lines);
-
-    let neighbours: Vec<(i32, i32)> = vec![(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
-
-    fn flash(map: &mut Array2D<u32>, point: (usize, usize), neighbours: &Vec<(i32, i32)>, count: &mut usize) {
+    fn flash(map: &mut Grid<u32>, point: Point, count: &mut usize) {
         *count += 1;
-        for neighbour in neighbours {
-            let adjacent_x = point.1 as i32 + neighbour.0;
-            let adjacent_y = point.0 as i32 + neighbour.1;
-
-            let is_within =
-                (0..map.num_columns() as i32).contains(&adjacent_x) && (0..map.num_rows() as i32).contains(&adjacent_y);
-            if !is_within {
-                continue;
-            }
-
-            let adjacent_point = (adjacent_y as usize, adjacent_x as usize);
-
-            map[adjacent_point] += 1;
-            if map[adjacent_point] == 10 {
-                flash(map, adjacent_point, neighbours, count)
+        for neighbour in map.neighbours8(point) {
+            map[neighbour] += 1;
+            if map[neighbour] == 10 {
+                flash(map, neighbour, count)
             }
         }
     }
 
     let mut count = 0;
     for _ in 0..100 {
-        for y in 0..map.num_rows() {
-            for x in 0..map.num_columns() {
-                let point = (y, x);
-                map[point] += 1;
-                if map[point] == 10 {
-                    flash(&mut map, point, &neighbours, &mut count);
-                }
+        for point in map.points() {
+            map[point] += 1;
+            if map[point] == 10 {
+                flash(&mut map, point, &mut count);
             }
         }
 
-        for y in 0..map.num_rows() {
-            for x in 0..map.num_columns() {
-                let point = (y, x);
-                if map[point] >= 10 {
-                    map[point] = 0;
-                }
+        for energy_level in map.iter_mut() {
+            if *energy_level >= 10 {
+                *energy_level = 0;
             }
         }
     }
 
-    count
+    Ok(count)
 }
 
-pub fn part2(input: &str) -> usize {
-    let lines = input
-        .lines()
-        .map(|instruction| {
-            instruction
-                .chars()
-                .map(|c| c.to_digit(10).unwrap())
-                .collect::<Vec<u32>>()
-        })
-        .collect::<Vec<Vec<u32>>>();
-
-    let mut map = Array2D::<u32>::from_rows(&lines);
-
-    let neighbours: Vec<(i32, i32)> = vec![(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
-
-    fn flash(map: &mut Array2D<u32>, point: (usize, usize), neighbours: &Vec<(i32, i32)>) {
-        for neighbour in neighbours {
-            let adjacent_x = point.1 as i32 + neighbour.0;
-            let adjacent_y = point.0 as i32 + neighbour.1;
-
-            let is_within =
-                (0..map.num_columns() as i32).contains(&adjacent_x) && (0..map.num_rows() as i32).contains(&adjacent_y);
-            if !is_within {
-                continue;
-            }
+pub fn part2(energy_levels: &Grid<u32>) -> Result<usize, PuzzleError> {
+    let mut map = energy_levels.clone();
 
-            let adjacent_point = (adjacent_y as usize, adjacent_x as usize);
-
-            map[adjacent_point] += 1;
-            if map[adjacent_point] == 10 {
-                flash(map, adjacent_point, neighbours)
+    fn flash(map: &mut Grid<u32>, point: Point) {
+        for neighbour in map.neighbours8(point) {
+            map[neighbour] += 1;
+            if map[neighbour] == 10 {
+                flash(map, neighbour)
             }
         }
     }
 
     let mut step = 1;
     loop {
-        for y in 0..map.num_rows() {
-            for x in 0..map.num_columns() {
-                let point = (y, x);
-                map[point] += 1;
-                if map[point] == 10 {
-                    flash(&mut map, point, &neighbours);
-                }
+        for point in map.points() {
+            map[point] += 1;
+            if map[point] == 10 {
+                flash(&mut map, point);
             }
         }
 
-        for y in 0..map.num_rows() {
-            for x in 0..map.num_columns() {
-                let point = (y, x);
-                if map[point] >= 10 {
-                    map[point] = 0;
-                }
+        for energy_level in map.iter_mut() {
+            if *energy_level >= 10 {
+                *energy_level = 0;
             }
         }
 
-        if map.elements_column_major_iter().all(|n| *n == 0) {
+        if map.iter().all(|n| *n == 0) {
             break;
         }
 
         step += 1
     }
 
-    step
+    Ok(step)
+}
+
+/// The energy level of every octopus, a digit each.
+///
+/// For example:
+///
+/// ```text example part1:1656 part2:195
+/// 5483143223
+/// 2745854711
+/// 5264556173
+/// 6141336146
+/// 6357385478
+/// 4167524645
+/// 2176841721
+/// 6882881134
+/// 4846848554
+/// 5283751526
+/// ```
+pub fn parse(input: &str) -> Result<Grid<u32>, PuzzleError> {
+    grid::parse_digits(input)
+}
+
+pub struct Day11;
+
+impl puzzle::Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Grid<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
@@ -141,7 +126,7 @@ mod tests {
 6882881134
 4846848554
 5283751526";
-        assert_eq!(crate::part1(input), 1656);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(1656));
     }
 
     #[test]
@@ -156,6 +141,14 @@ mod tests {
 6882881134
 4846848554
 5283751526";
-        assert_eq!(crate::part2(input), 195);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(195));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            crate::parse("5483143223\n27458547-1"),
+            Err(puzzle::PuzzleError::parse(2, 9, "-", "expected a digit"))
+        );
     }
 }
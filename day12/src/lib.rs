@@ -1,84 +1,122 @@
-use std::collections::{HashMap, HashSet};
-
-pub fn part1(input: &str) -> usize {
-    let mut multi_map: HashMap<&str, HashSet<&str>> = HashMap::new();
-
-    input
-        .lines()
-        .map(|line| {
-            let mut split = line.split('-');
-            (split.next().unwrap(), split.next().unwrap())
-        })
-        .for_each(|(start, end)| {
-            multi_map.entry(start).or_default().insert(end);
-            multi_map.entry(end).or_default().insert(start);
-        });
-
-    fn seek(start: &str, multi_map: &HashMap<&str, HashSet<&str>>, small_caves_visited: &HashSet<&str>) -> usize {
-        let mut count = 0;
-        for &destination in multi_map.get(start).unwrap_or(&HashSet::new()) {
-            if destination == "end" {
-                count += 1;
-            } else if destination == "start" {
-                //
-            } else if destination.chars().all(|c| c.is_lowercase()) {
-                if !small_caves_visited.contains(destination) {
-                    let mut small_caves_visited = small_caves_visited.clone();
-                    small_caves_visited.insert(destination);
-                    count += seek(destination, multi_map, &small_caves_visited);
-                }
-            } else {
-                count += seek(destination, multi_map, small_caves_visited);
-            }
-        }
-        count
-    }
+use graph::Graph;
+use puzzle::PuzzleError;
 
-    seek("start", &multi_map, &HashSet::new())
+pub fn part1(caves: &Graph<String>) -> Result<usize, PuzzleError> {
+    Ok(count_paths(caves, true))
 }
 
-pub fn part2(input: &str) -> usize {
-    let mut multi_map: HashMap<&str, HashSet<&str>> = HashMap::new();
-
-    input
-        .lines()
-        .map(|line| {
-            let mut split = line.split('-');
-            (split.next().unwrap(), split.next().unwrap())
-        })
-        .for_each(|(start, end)| {
-            multi_map.entry(start).or_default().insert(end);
-            multi_map.entry(end).or_default().insert(start);
-        });
-
-    fn seek(
-        start: &str,
-        multi_map: &HashMap<&str, HashSet<&str>>,
-        small_caves_visited: &HashSet<&str>,
-        used_double_visit: bool,
-    ) -> usize {
-        let mut count = 0;
-        for &destination in multi_map.get(start).unwrap_or(&HashSet::new()) {
-            if destination == "end" {
-                count += 1;
-            } else if destination == "start" {
-                //
-            } else if destination.chars().all(|c| c.is_lowercase()) {
-                if !small_caves_visited.contains(destination) {
-                    let mut small_caves_visited = small_caves_visited.clone();
-                    small_caves_visited.insert(destination);
-                    count += seek(destination, multi_map, &small_caves_visited, used_double_visit);
-                } else if !used_double_visit {
-                    count += seek(destination, multi_map, &small_caves_visited, true);
-                }
-            } else {
-                count += seek(destination, multi_map, small_caves_visited, used_double_visit);
+pub fn part2(caves: &Graph<String>) -> Result<usize, PuzzleError> {
+    // A single small cave can be visited twice, as long as it isn't the start
+    Ok(count_paths(caves, false))
+}
+
+/// Counts the paths from the start to the end that don't go back to the start. Each step carries whether the path has
+/// used its double visit yet, so a small cave can be entered a second time by the one step that sets it.
+fn count_paths(caves: &Graph<String>, used_double_visit: bool) -> usize {
+    graph::count_paths(
+        ("start", used_double_visit),
+        |&(cave, used)| {
+            caves.neighbours(cave).flat_map(move |destination| {
+                let destination = destination.as_str();
+                let double_visit = (!used && is_small(destination)).then_some((destination, true));
+                [Some((destination, used)), double_visit].into_iter().flatten()
+            })
+        },
+        |path, &(cave, used)| {
+            let visited = path.iter().any(|&(step, _)| step == cave);
+            match cave {
+                "start" => false,
+                _ if !is_small(cave) => true,
+                _ if used == path.last().unwrap().1 => !visited,
+                _ => visited,
             }
-        }
-        count
+        },
+        |&(cave, _)| cave == "end",
+    )
+}
+
+fn is_small(cave: &str) -> bool {
+    cave.chars().all(|c| c.is_lowercase())
+}
+
+/// A connection between two caves per line, like `start-A`.
+///
+/// The examples of the puzzle text:
+///
+/// ```text example part1:10 part2:36
+/// start-A
+/// start-b
+/// A-c
+/// A-b
+/// b-d
+/// A-end
+/// b-end
+/// ```
+///
+/// ```text example part1:19 part2:103
+/// dc-end
+/// HN-start
+/// start-kj
+/// dc-start
+/// dc-HN
+/// LN-dc
+/// HN-end
+/// kj-sa
+/// kj-HN
+/// kj-dc
+/// ```
+///
+/// ```text example part1:226 part2:3509
+/// fs-end
+/// he-DX
+/// fs-he
+/// start-DX
+/// pj-DX
+/// end-zg
+/// zg-sl
+/// zg-pj
+/// pj-he
+/// RW-he
+/// fs-DX
+/// pj-RW
+/// zg-RW
+/// start-pj
+/// he-WI
+/// zg-he
+/// pj-fs
+/// start-RW
+/// ```
+pub fn parse(input: &str) -> Result<Graph<String>, PuzzleError> {
+    let mut caves = Graph::new();
+    for (i, line) in input.lines().enumerate() {
+        let (start, end) = line
+            .split_once('-')
+            .ok_or_else(|| PuzzleError::parse(i + 1, 1, line, "expected `<cave>-<cave>`"))?;
+        caves.connect(start.to_string(), end.to_string(), 1);
+    }
+    Ok(caves)
+}
+
+pub struct Day12;
+
+impl puzzle::Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Graph<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
     }
 
-    seek("start", &multi_map, &HashSet::new(), false)
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
@@ -92,7 +130,7 @@ A-b
 b-d
 A-end
 b-end";
-        assert_eq!(crate::part1(input), 10);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(10));
     }
 
     #[test]
@@ -107,7 +145,7 @@ HN-end
 kj-sa
 kj-HN
 kj-dc";
-        assert_eq!(crate::part1(input), 19);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(19));
     }
 
     #[test]
@@ -130,7 +168,7 @@ he-WI
 zg-he
 pj-fs
 start-RW";
-        assert_eq!(crate::part1(input), 226);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(226));
     }
 
     #[test]
@@ -142,7 +180,7 @@ A-b
 b-d
 A-end
 b-end";
-        assert_eq!(crate::part2(input), 36);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(36));
     }
 
     #[test]
@@ -157,7 +195,7 @@ HN-end
 kj-sa
 kj-HN
 kj-dc";
-        assert_eq!(crate::part2(input), 103);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(103));
     }
 
     #[test]
@@ -180,6 +218,14 @@ he-WI
 zg-he
 pj-fs
 start-RW";
-        assert_eq!(crate::part2(input), 3509);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(3509));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            crate::parse("start-A\nstart b"),
+            Err(puzzle::PuzzleError::parse(2, 1, "start b", "expected `<cave>-<cave>`"))
+        );
     }
 }
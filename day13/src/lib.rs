@@ -1,70 +1,45 @@
-use array2d::Array2D;
+use grid::{Grid, Point};
+use puzzle::{parse_token, PuzzleError};
 use std::collections::HashSet;
 
-pub fn part1(input: &str) -> usize {
-    let (coordinates_str, instructions_str) = input.split_once("\n\n").unwrap();
-
-    let instructions = instructions_str
-        .lines()
-        .take(1)
-        .map(|line| line.split_once('=').unwrap())
-        .map(|(command, position_str)| (command, position_str.parse::<usize>().unwrap()))
-        .collect::<Vec<_>>();
-
-    let coordinates = coordinates_str
-        .lines()
-        .map(|coordinate| coordinate.split_once(',').unwrap())
-        .map(|(x, y)| (y.parse::<usize>().unwrap(), x.parse::<usize>().unwrap()));
+pub fn part1(manual: &Manual) -> Result<usize, PuzzleError> {
+    let instructions = &manual.folds[..manual.folds.len().min(1)];
 
     let mut folded_coordinates: HashSet<(usize, usize)> = HashSet::new();
-    for (mut y, mut x) in coordinates {
-        for &(command, position) in &instructions {
-            match command {
-                "fold along x" => {
+    for &(mut y, mut x) in &manual.dots {
+        for &fold in instructions {
+            match fold {
+                Fold::AlongX(position) => {
                     if x == position || x > 2 * position {
                         continue;
                     } else if x > position {
                         x = 2 * position - x
                     }
                 }
-                "fold along y" => {
+                Fold::AlongY(position) => {
                     if y == position || y > 2 * position {
                         continue;
                     } else if y > position {
                         y = 2 * position - y
                     }
                 }
-                _ => panic!("Unexpected instruction {}", command),
             }
         }
         folded_coordinates.insert((y, x));
     }
 
-    folded_coordinates.len()
+    Ok(folded_coordinates.len())
 }
 
-pub fn part2(input: &str) -> String {
-    let (coordinates_str, instructions_str) = input.split_once("\n\n").unwrap();
-
-    let instructions = instructions_str
-        .lines()
-        .map(|line| line.split_once('=').unwrap())
-        .map(|(command, position_str)| (command, position_str.parse::<usize>().unwrap()))
-        .collect::<Vec<_>>();
-
-    let coordinates = coordinates_str
-        .lines()
-        .map(|coordinate| coordinate.split_once(',').unwrap())
-        .map(|(x, y)| (y.parse::<usize>().unwrap(), x.parse::<usize>().unwrap()));
-
+pub fn part2(manual: &Manual) -> Result<String, PuzzleError> {
     let mut width = 0;
     let mut height = 0;
     let mut folded_coordinates: Vec<(usize, usize)> = Vec::new();
 
-    for (mut y, mut x) in coordinates {
-        for &(command, position) in &instructions {
-            match command {
-                "fold along x" => {
+    for &(mut y, mut x) in &manual.dots {
+        for &fold in &manual.folds {
+            match fold {
+                Fold::AlongX(position) => {
                     if x == position || x > 2 * position {
                         continue;
                     } else if x > position {
@@ -72,7 +47,7 @@ pub fn part2(input: &str) -> String {
                     }
                     width = position
                 }
-                "fold along y" => {
+                Fold::AlongY(position) => {
                     if y == position || y > 2 * position {
                         continue;
                     } else if y > position {
@@ -80,21 +55,123 @@ pub fn part2(input: &str) -> String {
                     }
                     height = position
                 }
-                _ => panic!("Unexpected instruction {}", command),
             }
         }
         folded_coordinates.push((y, x))
     }
 
-    let mut map = Array2D::<char>::filled_with('.', height, width);
-    folded_coordinates
-        .iter()
-        .for_each(|&folded_coordinate| map[folded_coordinate] = '#');
+    let mut map = Grid::new(width, height, '.');
+    for (y, x) in folded_coordinates {
+        let dot = map
+            .get_mut(Point::new(x, y))
+            .ok_or_else(|| PuzzleError::no_solution("some dots are left outside of the folded paper"))?;
+        *dot = '#';
+    }
+
+    Ok(map.to_string())
+}
 
-    map.rows_iter()
-        .map(|row| row.collect::<String>())
-        .collect::<Vec<String>>()
-        .join("\n")
+/// A fold of the paper along a vertical line, at a given x, or along an horizontal one, at a given y.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Fold {
+    AlongX(usize),
+    AlongY(usize),
+}
+
+/// The coordinates of the dots as (y, x), and the fold instructions in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Manual {
+    pub dots: Vec<(usize, usize)>,
+    pub folds: Vec<Fold>,
+}
+
+/// The dots, a blank line, then the fold instructions, like `fold along x=5`.
+///
+/// For example, part 2 drawing a square that only `inputs/day13/example.toml` can hold:
+///
+/// ```text example part1:17
+/// 6,10
+/// 0,14
+/// 9,10
+/// 0,3
+/// 10,4
+/// 4,11
+/// 6,0
+/// 6,12
+/// 4,1
+/// 0,13
+/// 10,12
+/// 3,4
+/// 3,0
+/// 8,4
+/// 1,10
+/// 2,14
+/// 8,10
+/// 9,0
+///
+/// fold along y=7
+/// fold along x=5
+/// ```
+pub fn parse(input: &str) -> Result<Manual, PuzzleError> {
+    let sections = inpututils::sections(input.lines());
+    let (coordinates_section, instructions_section) = match sections.as_slice() {
+        [coordinates_section, instructions_section] => (coordinates_section, instructions_section),
+        _ => {
+            let line_number = sections.first().map_or(1, |section| section.end_line());
+            return Err(PuzzleError::parse(
+                line_number,
+                1,
+                "",
+                "expected a blank line between the dots and the fold instructions",
+            ));
+        }
+    };
+
+    let coordinates = inpututils::coordinates::<usize>(coordinates_section.numbered_lines())?
+        .into_iter()
+        .map(|(x, y)| (y, x))
+        .collect();
+
+    let instructions = instructions_section
+        .numbered_lines()
+        .map(|(line_number, line)| match line.split_once('=') {
+            Some(("fold along x", position_str)) => Ok(Fold::AlongX(parse_token(line_number, line, position_str)?)),
+            Some(("fold along y", position_str)) => Ok(Fold::AlongY(parse_token(line_number, line, position_str)?)),
+            _ => Err(PuzzleError::parse(
+                line_number,
+                1,
+                line,
+                "expected `fold along x=<n>` or `fold along y=<n>`",
+            )),
+        })
+        .collect::<Result<Vec<_>, PuzzleError>>()?;
+
+    Ok(Manual {
+        dots: coordinates,
+        folds: instructions,
+    })
+}
+
+pub struct Day13;
+
+impl puzzle::Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Manual;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
@@ -122,7 +199,7 @@ mod tests {
 
 fold along y=7
 fold along x=5";
-        assert_eq!(crate::part1(input), 17);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(17));
     }
 
     #[test]
@@ -157,6 +234,32 @@ fold along x=5";
         .....\n\
         .....\
         ";
-        assert_eq!(crate::part2(input), output);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(output.to_string()));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            crate::parse("6,10\n0,x4\n\nfold along y=7"),
+            Err(puzzle::PuzzleError::parse(2, 3, "x4", "invalid digit found in string"))
+        );
+        assert_eq!(
+            crate::parse("6,10\n0,14\n\nfold along y=7\nfold along z=5"),
+            Err(puzzle::PuzzleError::parse(
+                5,
+                1,
+                "fold along z=5",
+                "expected `fold along x=<n>` or `fold along y=<n>`"
+            ))
+        );
+        assert_eq!(
+            crate::parse("6,10\n0,14"),
+            Err(puzzle::PuzzleError::parse(
+                3,
+                1,
+                "",
+                "expected a blank line between the dots and the fold instructions"
+            ))
+        );
     }
 }
@@ -1,30 +1,87 @@
 use itertools::{Itertools, MinMaxResult};
+use puzzle::PuzzleError;
 use std::collections::HashMap;
 
-pub fn part1(input: &str) -> usize {
-    evolve(input, 10)
+pub fn part1(instructions: &Instructions) -> Result<usize, PuzzleError> {
+    evolve(instructions, 10)
 }
 
-pub fn part2(input: &str) -> usize {
-    evolve(input, 40)
+pub fn part2(instructions: &Instructions) -> Result<usize, PuzzleError> {
+    evolve(instructions, 40)
 }
 
-fn evolve(input: &str, iterations: usize) -> usize {
-    let (template_str, rules_str) = input.split_once("\n\n").unwrap();
+/// The polymer template, never empty, and the element inserted between each pair of elements a rule is given for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instructions {
+    pub template: String,
+    pub rules: HashMap<(char, char), char>,
+}
+
+/// The polymer template, a blank line, then a pair insertion rule per line, like `CH -> B`.
+///
+/// For example:
+///
+/// ```text example part1:1588 part2:2188189693529
+/// NNCB
+///
+/// CH -> B
+/// HH -> N
+/// CB -> H
+/// NH -> C
+/// HB -> C
+/// HC -> B
+/// HN -> C
+/// NN -> C
+/// BH -> H
+/// NC -> B
+/// NB -> B
+/// BN -> B
+/// BB -> N
+/// BC -> B
+/// CC -> N
+/// CN -> C
+/// ```
+pub fn parse(input: &str) -> Result<Instructions, PuzzleError> {
+    let sections = inpututils::sections(input.lines());
+    let (template_str, rules_section) = match sections.as_slice() {
+        [template_section, rules_section] if template_section.lines.len() == 1 => {
+            (template_section.lines[0], rules_section)
+        }
+        _ => {
+            let line_number = sections.first().map_or(1, |section| section.first_line + 1);
+            return Err(PuzzleError::parse(
+                line_number,
+                1,
+                "",
+                "expected a blank line after the polymer template",
+            ));
+        }
+    };
+
+    let rules: HashMap<(char, char), char> = rules_section
+        .numbered_lines()
+        .map(
+            |(line_number, line)| match line.split(" -> ").collect_tuple::<(&str, &str)>() {
+                Some((lhs, rhs)) => match (lhs.chars().collect_tuple(), rhs.chars().collect_tuple()) {
+                    (Some(pair), Some((element,))) => Ok((pair, element)),
+                    _ => Err(PuzzleError::parse(line_number, 1, line, "expected `AB -> C`")),
+                },
+                None => Err(PuzzleError::parse(line_number, 1, line, "expected `AB -> C`")),
+            },
+        )
+        .collect::<Result<_, PuzzleError>>()?;
 
-    let template = template_str.chars().tuple_windows().counts();
+    Ok(Instructions {
+        template: template_str.to_string(),
+        rules,
+    })
+}
+
+fn evolve(instructions: &Instructions, iterations: usize) -> Result<usize, PuzzleError> {
+    // Sections have no blank lines, so the template has at least one element
+    let last_element = instructions.template.chars().last().unwrap();
 
-    let rules: HashMap<(char, char), char> = rules_str
-        .lines()
-        .filter_map(|l| l.split(" -> ").collect_tuple::<(&str, &str)>())
-        .map(|(lhs, rhs)| {
-            let mut chars = lhs.chars();
-            (
-                (chars.next().unwrap(), chars.next().unwrap()),
-                rhs.chars().next().unwrap(),
-            )
-        })
-        .collect();
+    let template = instructions.template.chars().tuple_windows().counts();
 
     fn grow(
         polymer: HashMap<(char, char), usize>,
@@ -42,18 +99,41 @@ fn evolve(input: &str, iterations: usize) -> usize {
         output
     }
 
-    let polymer = (0..iterations).fold(template, |polymer, _| grow(polymer, &rules));
+    let polymer = (0..iterations).fold(template, |polymer, _| grow(polymer, &instructions.rules));
 
     let mut histogram = HashMap::new();
     // The pairs are overlapping, so only count the 1st char of each pair and add the final char.
     for ((e1, _), count) in polymer {
         *histogram.entry(e1).or_insert(0) += count;
     }
-    *histogram.entry(template_str.chars().last().unwrap()).or_insert(0) += 1;
+    *histogram.entry(last_element).or_insert(0) += 1;
 
     match histogram.values().minmax() {
-        MinMaxResult::MinMax(&min, &max) => max - min,
-        _ => panic!("Min max not found"),
+        MinMaxResult::MinMax(&min, &max) => Ok(max - min),
+        MinMaxResult::OneElement(_) => Ok(0),
+        MinMaxResult::NoElements => unreachable!("The template has at least one element"),
+    }
+}
+
+pub struct Day14;
+
+impl puzzle::Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Instructions;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
     }
 }
 
@@ -79,7 +159,7 @@ BB -> N
 BC -> B
 CC -> N
 CN -> C";
-        assert_eq!(crate::part1(input), 1588);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(1588));
     }
 
     #[test]
@@ -102,6 +182,24 @@ BB -> N
 BC -> B
 CC -> N
 CN -> C";
-        assert_eq!(crate::part2(input), 2188189693529);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(2188189693529));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            crate::parse("NNCB\n\nCH -> B\nHHH -> N"),
+            Err(puzzle::PuzzleError::parse(4, 1, "HHH -> N", "expected `AB -> C`"))
+        );
+        assert_eq!(
+            crate::parse("NNCB\nCH -> B"),
+            Err(puzzle::PuzzleError::parse(
+                2,
+                1,
+                "",
+                "expected a blank line after the polymer template"
+            ))
+        );
+        assert_eq!(crate::part1(&crate::parse("N\n\nCH -> B").unwrap()), Ok(0));
     }
 }
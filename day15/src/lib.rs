@@ -1,179 +1,77 @@
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap, HashSet};
-
-fn dijkstra(start: Vertex, adjacency_list: &HashMap<Vertex, Vec<(Vertex, usize)>>) -> HashMap<Vertex, usize> {
-    let mut distances = HashMap::new();
-    let mut visited = HashSet::new();
-    let mut to_visit = BinaryHeap::new();
-
-    distances.insert(start, 0);
-    to_visit.push(Visit {
-        vertex: start,
-        distance: 0,
-    });
-
-    while let Some(Visit { vertex, distance }) = to_visit.pop() {
-        if !visited.insert(vertex) {
-            // Already visited this node
-            continue;
-        }
-
-        if let Some(neighbors) = adjacency_list.get(&vertex) {
-            for (neighbor, cost) in neighbors {
-                let new_distance = distance + cost;
-                let is_shorter = distances.get(&neighbor).map_or(true, |&current| new_distance < current);
-
-                if is_shorter {
-                    distances.insert(*neighbor, new_distance);
-                    to_visit.push(Visit {
-                        vertex: *neighbor,
-                        distance: new_distance,
-                    });
-                }
-            }
-        }
-    }
-
-    distances
-}
-
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-struct Vertex {
-    x: usize,
-    y: usize,
-}
-
-impl Vertex {
-    fn new(x: usize, y: usize) -> Vertex {
-        Vertex { x, y }
+use grid::{Grid, Point};
+use puzzle::PuzzleError;
+
+/// The risk level of every position, a digit each.
+///
+/// For example:
+///
+/// ```text example part1:40 part2:315
+/// 1163751742
+/// 1381373672
+/// 2136511328
+/// 3694931569
+/// 7463417111
+/// 1319128137
+/// 1359912421
+/// 3125421639
+/// 1293138521
+/// 2311944581
+/// ```
+pub fn parse(input: &str) -> Result<Grid<usize>, PuzzleError> {
+    let map = grid::parse_digits(input)?.map(|&risk| risk as usize);
+    if map.is_empty() {
+        return Err(PuzzleError::no_solution("the risk map is empty"));
     }
+    Ok(map)
 }
 
-#[derive(Debug)]
-struct Visit<V> {
-    vertex: V,
-    distance: usize,
+/// Lowest total risk of a path from the top left corner to the bottom right one, entering a cell costing its risk.
+fn lowest_total_risk(map: &Grid<usize>) -> usize {
+    let end = map.last_point().unwrap();
+    let path = graph::dijkstra(
+        Point::new(0, 0),
+        |&point| map.neighbours4(point).map(|neighbour| (neighbour, map[neighbour])),
+        |&point| point == end,
+    );
+    path.expect("every cell of a grid can be reached").cost
 }
 
-impl<V> Ord for Visit<V> {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.distance.cmp(&self.distance)
-    }
-}
-
-impl<V> PartialOrd for Visit<V> {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
+pub fn part1(map: &Grid<usize>) -> Result<usize, PuzzleError> {
+    Ok(lowest_total_risk(map))
 }
 
-impl<V> PartialEq for Visit<V> {
-    fn eq(&self, other: &Self) -> bool {
-        self.distance.eq(&other.distance)
-    }
-}
-
-impl<V> Eq for Visit<V> {}
-
-//
-
-pub fn part1(input: &str) -> usize {
-    let map = input
-        .lines()
-        .enumerate()
-        .map(|(y, line)| {
-            line.chars()
-                .map(|c| c.to_digit(10).unwrap() as usize)
-                .enumerate()
-                .map(|(x, weight)| (Vertex::new(x, y), weight))
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
-
-    let mut adjacency_list = HashMap::new();
-    for y in 0..map.len() {
-        for x in 0..map[y].len() {
-            let mut neightbours: Vec<(Vertex, usize)> = Vec::new();
-            if y > 0 {
-                neightbours.push(map[y - 1][x]);
-            }
-            if y < map.len() - 1 {
-                neightbours.push(map[y + 1][x]);
-            }
-            if x > 0 {
-                neightbours.push(map[y][x - 1]);
-            }
-            if x < map[y].len() - 1 {
-                neightbours.push(map[y][x + 1]);
-            }
-            adjacency_list.insert(map[y][x].0, neightbours);
+pub fn part2(map: &Grid<usize>) -> Result<usize, PuzzleError> {
+    let enlarged_map = map.tiled(5, 5, |&risk, tile| {
+        let mut risk = risk + tile.x + tile.y;
+        while risk > 9 {
+            risk -= 9
         }
-    }
-
-    let distances = dijkstra(map[0][0].0, &adjacency_list);
+        risk
+    });
 
-    *distances.get(&map.last().unwrap().last().unwrap().0).unwrap()
+    Ok(lowest_total_risk(&enlarged_map))
 }
 
-pub fn part2(input: &str) -> usize {
-    let map = input
-        .lines()
-        .enumerate()
-        .map(|(y, line)| {
-            line.chars()
-                .map(|c| c.to_digit(10).unwrap() as usize)
-                .enumerate()
-                .map(|(x, weight)| (Vertex::new(x, y), weight))
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
+pub struct Day15;
 
-    let mut enlarged_map: Vec<Vec<(Vertex, usize)>> = Vec::new();
+impl puzzle::Solution for Day15 {
+    const DAY: u8 = 15;
 
-    let col_len = map.len();
-    for i in 0..5 {
-        for y in 0..col_len {
-            let row = &map[y];
-            let row_len = row.len();
+    type Input = Grid<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-            let mut enlarged_row: Vec<(Vertex, usize)> = Vec::new();
-
-            for j in 0..5 {
-                for x in 0..row_len {
-                    let mut risk = row[x].1 + j + i;
-                    while risk > 9 {
-                        risk -= 9
-                    }
-                    enlarged_row.push((Vertex::new(i * col_len + y, j * row_len + x), risk))
-                }
-            }
-            enlarged_map.push(enlarged_row)
-        }
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
     }
 
-    let mut adjacency_list = HashMap::new();
-    for y in 0..enlarged_map.len() {
-        for x in 0..enlarged_map[y].len() {
-            let mut neightbours: Vec<(Vertex, usize)> = Vec::new();
-            if y > 0 {
-                neightbours.push(enlarged_map[y - 1][x]);
-            }
-            if y < enlarged_map.len() - 1 {
-                neightbours.push(enlarged_map[y + 1][x]);
-            }
-            if x > 0 {
-                neightbours.push(enlarged_map[y][x - 1]);
-            }
-            if x < enlarged_map[y].len() - 1 {
-                neightbours.push(enlarged_map[y][x + 1]);
-            }
-            adjacency_list.insert(enlarged_map[y][x].0, neightbours);
-        }
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
     }
 
-    let distances = dijkstra(enlarged_map[0][0].0, &adjacency_list);
-
-    *distances.get(&enlarged_map.last().unwrap().last().unwrap().0).unwrap()
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
@@ -190,7 +88,7 @@ mod tests {
 3125421639
 1293138521
 2311944581";
-        assert_eq!(crate::part1(input), 40);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(40));
     }
 
     #[test]
@@ -205,6 +103,27 @@ mod tests {
 3125421639
 1293138521
 2311944581";
-        assert_eq!(crate::part2(input), 315);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(315));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            crate::parse("116\n1x8"),
+            Err(puzzle::PuzzleError::parse(2, 2, "x", "expected a digit"))
+        );
+        assert_eq!(
+            crate::parse("116\n13"),
+            Err(puzzle::PuzzleError::parse(
+                2,
+                1,
+                "13",
+                "expected 3 characters like the first line"
+            ))
+        );
+        assert_eq!(
+            crate::parse(""),
+            Err(puzzle::PuzzleError::no_solution("the risk map is empty"))
+        );
     }
 }
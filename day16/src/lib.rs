@@ -1,18 +1,92 @@
-pub fn part1(input: &str) -> usize {
-    let mut iterator = input.chars().flat_map(to_binary);
-    let (version_sum, _, _) = parse_packet(&mut iterator);
-    version_sum
+use puzzle::PuzzleError;
+
+pub fn part1(packet: &Packet) -> Result<usize, PuzzleError> {
+    fn version_sum(packet: &Packet) -> usize {
+        packet.version + packet.sub_packets().iter().map(version_sum).sum::<usize>()
+    }
+
+    Ok(version_sum(packet))
 }
 
-pub fn part2(input: &str) -> usize {
-    let mut iterator = input.chars().flat_map(to_binary);
-    let (_, _, value) = parse_packet(&mut iterator);
-    value
+pub fn part2(packet: &Packet) -> Result<usize, PuzzleError> {
+    fn value(packet: &Packet) -> usize {
+        let values = packet.sub_packets().iter().map(value);
+        match (packet.type_id, &packet.contents) {
+            (_, Contents::Literal(literal_value)) => *literal_value,
+            (0, _) => values.sum(),
+            (1, _) => values.product(),
+            // Parsing makes sure there are sub-packets to compare, and two for comparisons
+            (2, _) => values.min().unwrap(),
+            (3, _) => values.max().unwrap(),
+            (5..=7, _) => {
+                let (first, second) = (value(&packet.sub_packets()[0]), value(&packet.sub_packets()[1]));
+                match packet.type_id {
+                    5 => (first > second) as usize,
+                    6 => (first < second) as usize,
+                    _ => (first == second) as usize,
+                }
+            }
+            _ => unreachable!("Unexpected type_id={}", packet.type_id),
+        }
+    }
+
+    Ok(value(packet))
+}
+
+/// A packet of the transmission. Type 4 holds a literal value, the others are operators on the packets they hold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    pub version: usize,
+    pub type_id: usize,
+    pub contents: Contents,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Contents {
+    Literal(usize),
+    Operator(Vec<Packet>),
+}
+
+impl Packet {
+    /// The packets an operator holds, none for a literal value.
+    pub fn sub_packets(&self) -> &[Packet] {
+        match &self.contents {
+            Contents::Literal(_) => &[],
+            Contents::Operator(sub_packets) => sub_packets,
+        }
+    }
+}
+
+/// The outermost packet of the hexadecimal transmission.
+///
+/// The examples of the puzzle text:
+///
+/// ```text example part1:16
+/// 8A004A801A8002F478
+/// ```
+///
+/// ```text example part2:3
+/// C200B40A82
+/// ```
+pub fn parse(input: &str) -> Result<Packet, PuzzleError> {
+    let mut iterator = to_bits(input)?.into_iter();
+    let (packet, _) = parse_packet(&mut iterator).map_err(|reason| PuzzleError::parse(1, 1, input, reason))?;
+    Ok(packet)
+}
+
+fn to_bits(input: &str) -> Result<Vec<usize>, PuzzleError> {
+    let mut bits = Vec::new();
+    for (i, hex) in input.trim_end().chars().enumerate() {
+        let decimal = hex
+            .to_digit(16)
+            .ok_or_else(|| PuzzleError::parse(1, i + 1, hex, "expected a hexadecimal digit"))?;
+        bits.extend(to_binary(decimal));
+    }
+    Ok(bits)
 }
 
-fn to_binary(hex: char) -> Vec<usize> {
+fn to_binary(mut decimal: u32) -> Vec<usize> {
     let mut bits: Vec<usize> = Vec::new();
-    let mut decimal = hex.to_digit(16).unwrap();
     for _ in 0..4 {
         bits.insert(0, (decimal % 2) as usize);
         decimal /= 2
@@ -20,28 +94,26 @@ fn to_binary(hex: char) -> Vec<usize> {
     bits
 }
 
-fn parse_packet(iterator: &mut impl Iterator<Item = usize>) -> (usize, usize, usize) {
-    let mut version = 0;
-    for _ in 0..3 {
-        version = (version << 1) + iterator.next().unwrap()
-    }
+fn read_bits(iterator: &mut impl Iterator<Item = usize>, count: usize) -> Result<usize, String> {
+    (0..count).try_fold(0, |value, _| match iterator.next() {
+        Some(bit) => Ok((value << 1) + bit),
+        None => Err("the transmission ends in the middle of a packet".to_string()),
+    })
+}
 
-    let mut type_id = 0;
-    for _ in 0..3 {
-        type_id = (type_id << 1) + iterator.next().unwrap()
-    }
+/// The packet and the number of bits it takes.
+fn parse_packet(iterator: &mut impl Iterator<Item = usize>) -> Result<(Packet, usize), String> {
+    let version = read_bits(iterator, 3)?;
+    let type_id = read_bits(iterator, 3)?;
 
-    let mut version_sum = version;
     let mut body_bit_count = 0;
 
-    let value = if type_id == 4 {
+    let contents = if type_id == 4 {
         // Literal value
         let mut literal_value = 0;
         loop {
-            let group_prefix = iterator.next().unwrap();
-            for _ in 0..4 {
-                literal_value = (literal_value << 1) + iterator.next().unwrap()
-            }
+            let group_prefix = read_bits(iterator, 1)?;
+            literal_value = (literal_value << 4) + read_bits(iterator, 4)?;
             body_bit_count += 5;
             if group_prefix == 0 {
                 break;
@@ -55,73 +127,91 @@ fn parse_packet(iterator: &mut impl Iterator<Item = usize>) -> (usize, usize, us
         }
         body_bit_count += filling_zeros;
 
-        literal_value as usize
+        Contents::Literal(literal_value)
     } else {
         // Operator
-        let lenght_type_id = iterator.next().unwrap();
+        let lenght_type_id = read_bits(iterator, 1)?;
         body_bit_count += 1;
 
-        let values: Vec<usize> = match lenght_type_id {
+        let sub_packets: Vec<Packet> = match lenght_type_id {
             0 => {
                 // If the length type ID is 0, then the next 15 bits are a number that represents
                 // the total length in bits of the sub-packets contained by this packet.
-                let mut length_in_bits = 0;
-                for _ in 0..15 {
-                    length_in_bits = (length_in_bits << 1) + iterator.next().unwrap()
-                }
+                let length_in_bits = read_bits(iterator, 15)?;
                 body_bit_count += 15;
 
-                let mut values: Vec<usize> = Vec::new();
+                let mut sub_packets: Vec<Packet> = Vec::new();
                 let mut sub_packages_bit_count = 0;
                 while sub_packages_bit_count < length_in_bits {
-                    let (version, bit_count, value) = parse_packet(iterator);
+                    let (sub_packet, bit_count) = parse_packet(iterator)?;
                     sub_packages_bit_count += bit_count;
                     body_bit_count += bit_count;
-                    version_sum += version;
-                    values.push(value);
+                    sub_packets.push(sub_packet);
                 }
                 if sub_packages_bit_count != length_in_bits {
-                    panic!(
-                        "Lenght in bits don't match, expected {} but got {}",
-                        length_in_bits, sub_packages_bit_count
-                    );
+                    return Err(format!(
+                        "the sub-packets take {} bits instead of {}",
+                        sub_packages_bit_count, length_in_bits
+                    ));
                 }
-                values
+                sub_packets
             }
             1 => {
                 // If the length type ID is 1, then the next 11 bits are a number that
                 // represents the number of sub-packets immediately contained by this packet.
-                let mut number_of_sub_packets = 0;
-                for _ in 0..11 {
-                    number_of_sub_packets = (number_of_sub_packets << 1) + iterator.next().unwrap()
-                }
+                let number_of_sub_packets = read_bits(iterator, 11)?;
                 body_bit_count += 11;
 
-                let mut values = Vec::new();
+                let mut sub_packets = Vec::new();
                 for _ in 0..number_of_sub_packets {
-                    let (version, bit_count, value) = parse_packet(iterator);
+                    let (sub_packet, bit_count) = parse_packet(iterator)?;
                     body_bit_count += bit_count;
-                    version_sum += version;
-                    values.push(value);
+                    sub_packets.push(sub_packet);
                 }
-                values
+                sub_packets
             }
-            _ => panic!("Unexpected lenght_type_id={}", lenght_type_id),
+            _ => unreachable!("Unexpected lenght_type_id={}", lenght_type_id),
         };
 
-        match type_id {
-            0 => values.iter().sum(),
-            1 => values.iter().product(),
-            2 => *values.iter().min().unwrap(),
-            3 => *values.iter().max().unwrap(),
-            5 => (values[0] > values[1]) as usize,
-            6 => (values[0] < values[1]) as usize,
-            7 => (values[0] == values[1]) as usize,
-            _ => panic!("Unexpected lenght_type_id={}", lenght_type_id),
+        match (type_id, sub_packets.len()) {
+            (2, 0) => return Err("a minimum packet has no sub-packets".to_string()),
+            (3, 0) => return Err("a maximum packet has no sub-packets".to_string()),
+            (5..=7, count) if count != 2 => {
+                return Err(format!("a comparison packet has {} sub-packets instead of 2", count))
+            }
+            _ => {}
         }
+        Contents::Operator(sub_packets)
     };
 
-    (version_sum, 6 + body_bit_count, value)
+    let packet = Packet {
+        version,
+        type_id,
+        contents,
+    };
+    Ok((packet, 6 + body_bit_count))
+}
+
+pub struct Day16;
+
+impl puzzle::Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Packet;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
@@ -129,31 +219,31 @@ mod tests {
     #[test]
     fn test_literal() {
         let input = "D2FE28";
-        assert_eq!(crate::part1(input), 6);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(6));
     }
 
     #[test]
     fn test_part1_a() {
         let input = "8A004A801A8002F478";
-        assert_eq!(crate::part1(input), 16);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(16));
     }
 
     #[test]
     fn test_part1_b() {
         let input = "620080001611562C8802118E34";
-        assert_eq!(crate::part1(input), 12);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(12));
     }
 
     #[test]
     fn test_part1_c() {
         let input = "C0015000016115A2E0802F182340";
-        assert_eq!(crate::part1(input), 23);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(23));
     }
 
     #[test]
     fn test_part1_v() {
         let input = "A0016C880162017C3686B18A3D4780";
-        assert_eq!(crate::part1(input), 31);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(31));
     }
 
     //
@@ -161,48 +251,75 @@ mod tests {
     #[test]
     fn test_part2_a() {
         let input = "C200B40A82";
-        assert_eq!(crate::part2(input), 3);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(3));
     }
 
     #[test]
     fn test_part2_b() {
         let input = "04005AC33890";
-        assert_eq!(crate::part2(input), 54);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(54));
     }
 
     #[test]
     fn test_part2_c() {
         let input = "880086C3E88112";
-        assert_eq!(crate::part2(input), 7);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(7));
     }
 
     #[test]
     fn test_part2_d() {
         let input = "CE00C43D881120";
-        assert_eq!(crate::part2(input), 9);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(9));
     }
 
     #[test]
     fn test_part2_e() {
         let input = "D8005AC2A8F0";
-        assert_eq!(crate::part2(input), 1);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(1));
     }
 
     #[test]
     fn test_part2_f() {
         let input = "F600BC2D8F";
-        assert_eq!(crate::part2(input), 0);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(0));
     }
 
     #[test]
     fn test_part2_g() {
         let input = "9C005AC2F8F0";
-        assert_eq!(crate::part2(input), 0);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(0));
     }
 
     #[test]
     fn test_part2_h() {
         let input = "9C0141080250320F1802104A08";
-        assert_eq!(crate::part2(input), 1);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(1));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            crate::parse("D2FG28"),
+            Err(puzzle::PuzzleError::parse(1, 4, "G", "expected a hexadecimal digit"))
+        );
+        assert_eq!(
+            crate::parse("D2FE"),
+            Err(puzzle::PuzzleError::parse(
+                1,
+                1,
+                "D2FE",
+                "the transmission ends in the middle of a packet"
+            ))
+        );
+        // A "greater than" packet with three literal sub-packets
+        assert_eq!(
+            crate::parse("1600C40881102"),
+            Err(puzzle::PuzzleError::parse(
+                1,
+                1,
+                "1600C40881102",
+                "a comparison packet has 3 sub-packets instead of 2"
+            ))
+        );
     }
 }
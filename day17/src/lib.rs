@@ -1,23 +1,57 @@
-pub fn part1(input: &str) -> i64 {
-    launch_probe(input).0
+use puzzle::{parse_token, PuzzleError};
+
+pub fn part1(target: &TargetArea) -> Result<i64, PuzzleError> {
+    launch_probe(target).map(|(highest_y, _)| highest_y)
+}
+
+pub fn part2(target: &TargetArea) -> Result<usize, PuzzleError> {
+    launch_probe(target).map(|(_, hit_count)| hit_count)
 }
 
-pub fn part2(input: &str) -> usize {
-    launch_probe(input).1
+/// The ranges of x and y where the probe must be after some step, bounds included.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TargetArea {
+    pub min_x: i64,
+    pub max_x: i64,
+    pub min_y: i64,
+    pub max_y: i64,
 }
 
-fn launch_probe(input: &str) -> (i64, usize) {
-    let (x_range, y_range) = input
+/// `target area: x=<min>..<max>, y=<min>..<max>`
+///
+/// For example:
+///
+/// ```text example part1:45 part2:112
+/// target area: x=20..30, y=-10..-5
+/// ```
+pub fn parse(input: &str) -> Result<TargetArea, PuzzleError> {
+    let line = input.lines().next().unwrap_or_default();
+    let format_error = || PuzzleError::parse(1, 1, line, "expected `target area: x=<min>..<max>, y=<min>..<max>`");
+    let (x_range, y_range) = line
         .strip_prefix("target area: x=")
-        .unwrap()
-        .split_once(", y=")
-        .unwrap();
-    let (min_x, max_x) = x_range.split_once("..").unwrap();
-    let (min_y, max_y) = y_range.split_once("..").unwrap();
-    let min_x = min_x.parse::<i64>().unwrap();
-    let max_x = max_x.parse::<i64>().unwrap();
-    let min_y = min_y.parse::<i64>().unwrap();
-    let max_y = max_y.parse::<i64>().unwrap();
+        .and_then(|ranges| ranges.split_once(", y="))
+        .ok_or_else(format_error)?;
+    let (min_x, max_x) = x_range.split_once("..").ok_or_else(format_error)?;
+    let (min_y, max_y) = y_range.split_once("..").ok_or_else(format_error)?;
+    let min_x = parse_token::<i64>(1, line, min_x)?;
+    let max_x = parse_token::<i64>(1, line, max_x)?;
+    let min_y = parse_token::<i64>(1, line, min_y)?;
+    let max_y = parse_token::<i64>(1, line, max_y)?;
+    Ok(TargetArea {
+        min_x,
+        max_x,
+        min_y,
+        max_y,
+    })
+}
+
+fn launch_probe(target: &TargetArea) -> Result<(i64, usize), PuzzleError> {
+    let TargetArea {
+        min_x,
+        max_x,
+        min_y,
+        max_y,
+    } = *target;
 
     // Note: Initially implemented with brute force; the altenative would be calculating all values using inequalities
     // ix = 0
@@ -64,35 +98,84 @@ fn launch_probe(input: &str) -> (i64, usize) {
         }
     }
 
-    (
-        *successful_max_height.iter().max().unwrap(),
-        successful_max_height.len(),
-    )
+    match successful_max_height.iter().max() {
+        Some(&highest_y) => Ok((highest_y, successful_max_height.len())),
+        None => Err(PuzzleError::no_solution("no launch velocity hits the target area")),
+    }
+}
+
+pub struct Day17;
+
+impl puzzle::Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = TargetArea;
+    type Answer1 = i64;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
 }
+
 #[cfg(test)]
 mod tests {
     #[test]
     fn test_part1() {
         let input = "target area: x=20..30, y=-10..-5";
-        assert_eq!(crate::part1(input), 45);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(45));
     }
 
     #[test]
     fn test_part1_failed_attempts() {
         let input = "target area: x=175..227, y=-134..-79";
-        assert_ne!(crate::part1(input), 1176);
-        assert_ne!(crate::part1(input), 4950);
+        assert_ne!(crate::part1(&crate::parse(input).unwrap()), Ok(1176));
+        assert_ne!(crate::part1(&crate::parse(input).unwrap()), Ok(4950));
     }
 
     #[test]
     fn test_part2() {
         let input = "target area: x=20..30, y=-10..-5";
-        assert_eq!(crate::part2(input), 112);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(112));
     }
 
     #[test]
     fn test_part2_failed_attempts() {
         let input = "target area: x=175..227, y=-134..-79";
-        assert_ne!(crate::part2(input), 1360);
+        assert_ne!(crate::part2(&crate::parse(input).unwrap()), Ok(1360));
+    }
+
+    #[test]
+    fn test_errors() {
+        let input = "target area: x=20..30, y=-10..-x";
+        assert_eq!(
+            crate::parse(input),
+            Err(puzzle::PuzzleError::parse(1, 31, "-x", "invalid digit found in string"))
+        );
+        let input = "target area: x=20..30";
+        assert_eq!(
+            crate::parse(input),
+            Err(puzzle::PuzzleError::parse(
+                1,
+                1,
+                input,
+                "expected `target area: x=<min>..<max>, y=<min>..<max>`"
+            ))
+        );
+        let input = "target area: x=-30..-20, y=-10..-5";
+        assert_eq!(
+            crate::part2(&crate::parse(input).unwrap()),
+            Err(puzzle::PuzzleError::no_solution(
+                "no launch velocity hits the target area"
+            ))
+        );
     }
 }
@@ -1,7 +1,9 @@
-type SnailfishNumber = (SnailfishElement, SnailfishElement);
+use puzzle::PuzzleError;
+
+pub type SnailfishNumber = (SnailfishElement, SnailfishElement);
 
 #[derive(PartialEq, Eq, Hash, Clone, Debug)]
-enum SnailfishElement {
+pub enum SnailfishElement {
     RegularNumber(u8),
     Pair(Box<SnailfishNumber>),
 }
@@ -16,20 +18,20 @@ impl SnailfishElement {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let sum = lines_sum(input);
-    magnitude(sum)
+pub fn part1(numbers: &[SnailfishNumber]) -> Result<usize, PuzzleError> {
+    let sum = lines_sum(numbers)?;
+    Ok(magnitude(sum))
 }
 
-pub fn part2(input: &str) -> usize {
-    let lines = input.lines().map(|line| parse_line(line)).collect::<Vec<_>>();
-    lines
-        .clone()
+pub fn part2(lines: &[SnailfishNumber]) -> Result<usize, PuzzleError> {
+    if lines.len() < 2 {
+        return Err(PuzzleError::no_solution("needs at least two numbers to add"));
+    }
+    Ok(lines
         .iter()
         .enumerate()
         .flat_map(|(i, a)| {
             lines
-                .clone()
                 .iter()
                 .enumerate()
                 .filter_map(|(j, b)| if j != i { Some(b) } else { None })
@@ -38,36 +40,109 @@ pub fn part2(input: &str) -> usize {
                 .collect::<Vec<_>>()
         })
         .max()
-        .unwrap()
+        .unwrap())
+}
+
+/// A snailfish number per line.
+///
+/// For example:
+///
+/// ```text example part1:4140 part2:3993
+/// [[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+/// [[[5,[2,8]],4],[5,[[9,9],0]]]
+/// [6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+/// [[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+/// [[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+/// [[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+/// [[[[5,4],[7,7]],8],[[8,3],8]]
+/// [[9,3],[[9,9],[6,[4,9]]]]
+/// [[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+/// [[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]
+/// ```
+pub fn parse(input: &str) -> Result<Vec<SnailfishNumber>, PuzzleError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|error| error.at_line(i + 1)))
+        .collect()
 }
 
-fn parse_line(input: &str) -> SnailfishNumber {
-    let mut chars = input.chars();
-    chars.next(); // Ignore opening bracket
-    parse_number(&mut chars)
+fn parse_line(input: &str) -> Result<SnailfishNumber, PuzzleError> {
+    let end_column = input.chars().count() + 1;
+    let mut chars = input.chars().enumerate().map(|(i, c)| (i + 1, c));
+    expect(&mut chars, '[', end_column)?;
+    let number = parse_number(&mut chars, 0, end_column)?;
+    match chars.next() {
+        Some((column, c)) => Err(PuzzleError::parse(
+            1,
+            column,
+            c,
+            "unexpected character after the number",
+        )),
+        None => Ok(number),
+    }
+}
+
+fn next_char(
+    iterator: &mut impl Iterator<Item = (usize, char)>,
+    end_column: usize,
+) -> Result<(usize, char), PuzzleError> {
+    iterator
+        .next()
+        .ok_or_else(|| PuzzleError::parse(1, end_column, "", "the line ends in the middle of a number"))
+}
+
+fn expect(
+    iterator: &mut impl Iterator<Item = (usize, char)>,
+    expected: char,
+    end_column: usize,
+) -> Result<(), PuzzleError> {
+    match next_char(iterator, end_column)? {
+        (_, c) if c == expected => Ok(()),
+        (column, c) => Err(PuzzleError::parse(1, column, c, format!("expected `{}`", expected))),
+    }
 }
 
-fn parse_number(iterator: &mut impl Iterator<Item = char>) -> SnailfishNumber {
-    let left = parse_element(iterator);
-    let comma = iterator.next().unwrap();
-    assert_eq!(comma, ',');
-    let right = parse_element(iterator);
-    let closing = iterator.next().unwrap();
-    assert_eq!(closing, ']');
-    (left, right)
+/// Parses the rest of a pair whose opening bracket was just read, nested `depth` pairs deep.
+fn parse_number(
+    iterator: &mut impl Iterator<Item = (usize, char)>,
+    depth: usize,
+    end_column: usize,
+) -> Result<SnailfishNumber, PuzzleError> {
+    let left = parse_element(iterator, depth, end_column)?;
+    expect(iterator, ',', end_column)?;
+    let right = parse_element(iterator, depth, end_column)?;
+    expect(iterator, ']', end_column)?;
+    Ok((left, right))
 }
 
-fn parse_element(iterator: &mut impl Iterator<Item = char>) -> SnailfishElement {
-    let next = iterator.next().unwrap();
-    if next == '[' {
-        SnailfishElement::Pair(Box::new(parse_number(iterator)))
-    } else {
-        SnailfishElement::RegularNumber(next.to_digit(10).unwrap() as u8)
+fn parse_element(
+    iterator: &mut impl Iterator<Item = (usize, char)>,
+    depth: usize,
+    end_column: usize,
+) -> Result<SnailfishElement, PuzzleError> {
+    match next_char(iterator, end_column)? {
+        // Reduction only knows how to explode pairs of regular numbers
+        (column, '[') if depth == 4 => Err(PuzzleError::parse(
+            1,
+            column,
+            '[',
+            "pairs can't be nested inside more than 4 pairs",
+        )),
+        (_, '[') => Ok(SnailfishElement::Pair(Box::new(parse_number(
+            iterator,
+            depth + 1,
+            end_column,
+        )?))),
+        (column, c) => match c.to_digit(10) {
+            Some(n) => Ok(SnailfishElement::RegularNumber(n as u8)),
+            None => Err(PuzzleError::parse(1, column, c, "expected a digit or `[`")),
+        },
     }
 }
 
 #[derive(PartialEq, Debug)]
-enum Result {
+enum Reduction {
     Explode(u8, u8),
     Exploding(u8, u8, SnailfishNumber),
     ExplodeLeft(u8, SnailfishNumber),
@@ -141,29 +216,29 @@ fn sum_to_rightmost_element(element: &SnailfishElement, value: u8) -> Option<Sna
 
 fn outer_reduce(number: &SnailfishNumber) -> Option<SnailfishNumber> {
     match reduce(number, Some(0)) {
-        Result::Explode(_, _) => return Some(number.clone()),
-        Result::Exploding(_, _, number) => return Some(number),
-        Result::ExplodeLeft(_, number) => return Some(number),
-        Result::ExplodeRight(_, number) => return Some(number),
-        Result::Modified(number) => return Some(number),
-        Result::None => {}
+        Reduction::Explode(_, _) => return Some(number.clone()),
+        Reduction::Exploding(_, _, number) => return Some(number),
+        Reduction::ExplodeLeft(_, number) => return Some(number),
+        Reduction::ExplodeRight(_, number) => return Some(number),
+        Reduction::Modified(number) => return Some(number),
+        Reduction::None => {}
     }
     match reduce(number, None) {
-        Result::Explode(_, _) => Some(number.clone()),
-        Result::Exploding(_, _, number) => Some(number),
-        Result::ExplodeLeft(_, number) => Some(number),
-        Result::ExplodeRight(_, number) => Some(number),
-        Result::Modified(number) => Some(number),
-        Result::None => None,
+        Reduction::Explode(_, _) => Some(number.clone()),
+        Reduction::Exploding(_, _, number) => Some(number),
+        Reduction::ExplodeLeft(_, number) => Some(number),
+        Reduction::ExplodeRight(_, number) => Some(number),
+        Reduction::Modified(number) => Some(number),
+        Reduction::None => None,
     }
 }
 
-fn reduce(number: &SnailfishNumber, nested_level: Option<u8>) -> Result {
+fn reduce(number: &SnailfishNumber, nested_level: Option<u8>) -> Reduction {
     let (left_element, right_element) = number;
 
     if let Some(nested_level) = nested_level {
         if nested_level == 4 {
-            return Result::Explode(number.0.clone().regular_number(), number.1.clone().regular_number());
+            return Reduction::Explode(number.0.clone().regular_number(), number.1.clone().regular_number());
         }
     }
 
@@ -176,48 +251,52 @@ fn reduce(number: &SnailfishNumber, nested_level: Option<u8>) -> Result {
                     SnailfishElement::RegularNumber(split_left),
                     SnailfishElement::RegularNumber(split_right),
                 )));
-                return Result::Modified((new_left_element, right_element.clone()));
+                return Reduction::Modified((new_left_element, right_element.clone()));
             }
         }
         SnailfishElement::Pair(boxed_number) => {
             match reduce(&**boxed_number, nested_level.map(|l| l + 1)) {
-                Result::Explode(left_value, right_value) => {
+                Reduction::Explode(left_value, right_value) => {
                     let new_left_element = SnailfishElement::RegularNumber(0);
                     // Try to add it on the right branch
                     return match sum_to_leftmost_element(right_element, right_value) {
                         Some(new_right_element) => {
-                            Result::ExplodeLeft(left_value, (new_left_element, new_right_element))
+                            Reduction::ExplodeLeft(left_value, (new_left_element, new_right_element))
+                        }
+                        None => {
+                            Reduction::Exploding(left_value, right_value, (new_left_element, right_element.clone()))
                         }
-                        None => Result::Exploding(left_value, right_value, (new_left_element, right_element.clone())),
                     };
                 }
-                Result::Exploding(left_value, right_value, new_left_number) => {
+                Reduction::Exploding(left_value, right_value, new_left_number) => {
                     let new_left_element = SnailfishElement::Pair(Box::new(new_left_number));
                     // Try to add it on the right branch
                     return match sum_to_leftmost_element(right_element, right_value) {
                         Some(new_right_element) => {
-                            Result::ExplodeLeft(left_value, (new_left_element, new_right_element))
+                            Reduction::ExplodeLeft(left_value, (new_left_element, new_right_element))
+                        }
+                        None => {
+                            Reduction::Exploding(left_value, right_value, (new_left_element, right_element.clone()))
                         }
-                        None => Result::Exploding(left_value, right_value, (new_left_element, right_element.clone())),
                     };
                 }
-                Result::ExplodeLeft(left_value, new_left_number) => {
+                Reduction::ExplodeLeft(left_value, new_left_number) => {
                     // Cannot fix it, just forward up in the hierarchy
                     let new_left_element = SnailfishElement::Pair(Box::new(new_left_number));
-                    return Result::ExplodeLeft(left_value, (new_left_element, right_element.clone()));
+                    return Reduction::ExplodeLeft(left_value, (new_left_element, right_element.clone()));
                 }
-                Result::ExplodeRight(right_value, new_left_number) => {
+                Reduction::ExplodeRight(right_value, new_left_number) => {
                     let new_left_element = SnailfishElement::Pair(Box::new(new_left_number));
                     return match sum_to_leftmost_element(right_element, right_value) {
-                        Some(new_right_element) => Result::Modified((new_left_element, new_right_element)),
-                        None => Result::ExplodeRight(right_value, (new_left_element, right_element.clone())),
+                        Some(new_right_element) => Reduction::Modified((new_left_element, new_right_element)),
+                        None => Reduction::ExplodeRight(right_value, (new_left_element, right_element.clone())),
                     };
                 }
-                Result::Modified(new_left_number) => {
+                Reduction::Modified(new_left_number) => {
                     let new_left_element = SnailfishElement::Pair(Box::new(new_left_number));
-                    return Result::Modified((new_left_element, right_element.clone()));
+                    return Reduction::Modified((new_left_element, right_element.clone()));
                 }
-                Result::None => {}
+                Reduction::None => {}
             }
         }
     }
@@ -231,53 +310,57 @@ fn reduce(number: &SnailfishNumber, nested_level: Option<u8>) -> Result {
                     SnailfishElement::RegularNumber(split_left),
                     SnailfishElement::RegularNumber(split_right),
                 )));
-                return Result::Modified((left_element.clone(), new_right_element));
+                return Reduction::Modified((left_element.clone(), new_right_element));
             }
         }
         SnailfishElement::Pair(boxed_number) => {
             match reduce(&**boxed_number, nested_level.map(|l| l + 1)) {
-                Result::Explode(left_value, right_value) => {
+                Reduction::Explode(left_value, right_value) => {
                     let new_right_element = SnailfishElement::RegularNumber(0);
                     // Try to add it on the right branch
                     return match sum_to_rightmost_element(left_element, left_value) {
                         Some(new_left_element) => {
-                            Result::ExplodeRight(right_value, (new_left_element, new_right_element))
+                            Reduction::ExplodeRight(right_value, (new_left_element, new_right_element))
+                        }
+                        None => {
+                            Reduction::Exploding(left_value, right_value, (left_element.clone(), new_right_element))
                         }
-                        None => Result::Exploding(left_value, right_value, (left_element.clone(), new_right_element)),
                     };
                 }
-                Result::Exploding(left_value, right_value, new_right_number) => {
+                Reduction::Exploding(left_value, right_value, new_right_number) => {
                     let new_right_element = SnailfishElement::Pair(Box::new(new_right_number));
                     // Try to add it on the right branch
                     return match sum_to_rightmost_element(left_element, left_value) {
                         Some(new_left_element) => {
-                            Result::ExplodeRight(right_value, (new_left_element, new_right_element))
+                            Reduction::ExplodeRight(right_value, (new_left_element, new_right_element))
+                        }
+                        None => {
+                            Reduction::Exploding(left_value, right_value, (left_element.clone(), new_right_element))
                         }
-                        None => Result::Exploding(left_value, right_value, (left_element.clone(), new_right_element)),
                     };
                 }
-                Result::ExplodeLeft(left_value, new_right_number) => {
+                Reduction::ExplodeLeft(left_value, new_right_number) => {
                     let new_right_element = SnailfishElement::Pair(Box::new(new_right_number));
                     return match sum_to_rightmost_element(left_element, left_value) {
-                        Some(new_left_element) => Result::Modified((new_left_element, new_right_element)),
-                        None => Result::ExplodeLeft(left_value, (left_element.clone(), new_right_element)),
+                        Some(new_left_element) => Reduction::Modified((new_left_element, new_right_element)),
+                        None => Reduction::ExplodeLeft(left_value, (left_element.clone(), new_right_element)),
                     };
                 }
-                Result::ExplodeRight(right_value, new_right_number) => {
+                Reduction::ExplodeRight(right_value, new_right_number) => {
                     // Cannot fix it, just forward up in the hierarchy
                     let new_right_element = SnailfishElement::Pair(Box::new(new_right_number));
-                    return Result::ExplodeRight(right_value, (left_element.clone(), new_right_element));
+                    return Reduction::ExplodeRight(right_value, (left_element.clone(), new_right_element));
                 }
-                Result::Modified(new_right_number) => {
+                Reduction::Modified(new_right_number) => {
                     let new_right_element = SnailfishElement::Pair(Box::new(new_right_number));
-                    return Result::Modified((left_element.clone(), new_right_element));
+                    return Reduction::Modified((left_element.clone(), new_right_element));
                 }
-                Result::None => {}
+                Reduction::None => {}
             }
         }
     }
 
-    Result::None
+    Reduction::None
 }
 
 fn sum(a: SnailfishNumber, b: SnailfishNumber) -> SnailfishNumber {
@@ -301,8 +384,34 @@ fn magnitude(snailfish_number: SnailfishNumber) -> usize {
     3 * left_value + 2 * right_value
 }
 
-fn lines_sum(input: &str) -> SnailfishNumber {
-    input.lines().map(|line| parse_line(line)).reduce(sum).unwrap()
+fn lines_sum(numbers: &[SnailfishNumber]) -> Result<SnailfishNumber, PuzzleError> {
+    numbers
+        .iter()
+        .cloned()
+        .reduce(sum)
+        .ok_or_else(|| PuzzleError::no_solution("there are no numbers to add"))
+}
+
+pub struct Day18;
+
+impl puzzle::Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = Vec<SnailfishNumber>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
 }
 
 #[cfg(test)]
@@ -310,94 +419,92 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        crate::parse_line("[1,2]");
-        crate::parse_line("[[1,2],3]");
-        crate::parse_line("[9,[8,7]]");
-        crate::parse_line("[[1,9],[8,5]]");
-        crate::parse_line("[[[[1,2],[3,4]],[[5,6],[7,8]]],9]");
-        crate::parse_line("[[[9,[3,8]],[[0,9],6]],[[[3,7],[4,9]],3]]");
-        crate::parse_line("[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]");
+        crate::parse_line("[1,2]").unwrap();
+        crate::parse_line("[[1,2],3]").unwrap();
+        crate::parse_line("[9,[8,7]]").unwrap();
+        crate::parse_line("[[1,9],[8,5]]").unwrap();
+        crate::parse_line("[[[[1,2],[3,4]],[[5,6],[7,8]]],9]").unwrap();
+        crate::parse_line("[[[9,[3,8]],[[0,9],6]],[[[3,7],[4,9]],3]]").unwrap();
+        crate::parse_line("[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]").unwrap();
     }
 
     #[test]
     fn test_explosion_a() {
-        let number = crate::parse_line("[[[[[9,8],1],2],3],4]");
+        let number = crate::parse_line("[[[[[9,8],1],2],3],4]").unwrap();
         let reduced = crate::outer_reduce(&number);
-        let expected = Some(crate::parse_line("[[[[0,9],2],3],4]"));
+        let expected = Some(crate::parse_line("[[[[0,9],2],3],4]").unwrap());
         assert_eq!(reduced, expected);
     }
 
     #[test]
     fn test_explosion_b() {
-        let number = crate::parse_line("[7,[6,[5,[4,[3,2]]]]]");
+        let number = crate::parse_line("[7,[6,[5,[4,[3,2]]]]]").unwrap();
         let reduced = crate::outer_reduce(&number);
-        let expected = Some(crate::parse_line("[7,[6,[5,[7,0]]]]"));
+        let expected = Some(crate::parse_line("[7,[6,[5,[7,0]]]]").unwrap());
         assert_eq!(reduced, expected);
     }
 
     #[test]
     fn test_explosion_c() {
-        let number = crate::parse_line("[[6,[5,[4,[3,2]]]],1]");
+        let number = crate::parse_line("[[6,[5,[4,[3,2]]]],1]").unwrap();
         let reduced = crate::outer_reduce(&number);
-        let expected = Some(crate::parse_line("[[6,[5,[7,0]]],3]"));
+        let expected = Some(crate::parse_line("[[6,[5,[7,0]]],3]").unwrap());
         assert_eq!(reduced, expected);
     }
 
     #[test]
     fn test_explosion_d() {
-        let number = crate::parse_line("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]");
+        let number = crate::parse_line("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]").unwrap();
         let reduced = crate::outer_reduce(&number);
-        let expected = Some(crate::parse_line("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]"));
+        let expected = Some(crate::parse_line("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]").unwrap());
         assert_eq!(reduced, expected);
     }
 
     #[test]
     fn test_explosion_e() {
-        let number = crate::parse_line("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]");
+        let number = crate::parse_line("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]").unwrap();
         let reduced = crate::outer_reduce(&number);
-        let expected = Some(crate::parse_line("[[3,[2,[8,0]]],[9,[5,[7,0]]]]"));
+        let expected = Some(crate::parse_line("[[3,[2,[8,0]]],[9,[5,[7,0]]]]").unwrap());
         assert_eq!(reduced, expected);
     }
 
     #[test]
     fn test_split() {
-        let a = crate::parse_line("[[[[4,3],4],4],[7,[[8,4],9]]]");
-        let b = crate::parse_line("[1,1]");
+        let a = crate::parse_line("[[[[4,3],4],4],[7,[[8,4],9]]]").unwrap();
+        let b = crate::parse_line("[1,1]").unwrap();
         let reduced = crate::sum(a, b);
-        let expected = crate::parse_line("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+        let expected = crate::parse_line("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").unwrap();
         assert_eq!(reduced, expected);
     }
 
     #[test]
     fn test_magnitudes() {
-        assert_eq!(crate::magnitude(crate::parse_line("[9,1]")), 29);
-        assert_eq!(crate::magnitude(crate::parse_line("[[1,2],[[3,4],5]]")), 143);
+        assert_eq!(crate::magnitude(crate::parse_line("[9,1]").unwrap()), 29);
+        assert_eq!(crate::magnitude(crate::parse_line("[[1,2],[[3,4],5]]").unwrap()), 143);
         assert_eq!(
-            crate::magnitude(crate::parse_line("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]")),
+            crate::magnitude(crate::parse_line("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").unwrap()),
             1384
         );
         assert_eq!(
-            crate::magnitude(crate::parse_line("[[[[1,1],[2,2]],[3,3]],[4,4]]")),
+            crate::magnitude(crate::parse_line("[[[[1,1],[2,2]],[3,3]],[4,4]]").unwrap()),
             445
         );
         assert_eq!(
-            crate::magnitude(crate::parse_line("[[[[3,0],[5,3]],[4,4]],[5,5]]")),
+            crate::magnitude(crate::parse_line("[[[[3,0],[5,3]],[4,4]],[5,5]]").unwrap()),
             791
         );
         assert_eq!(
-            crate::magnitude(crate::parse_line("[[[[5,0],[7,4]],[5,5]],[6,6]]")),
+            crate::magnitude(crate::parse_line("[[[[5,0],[7,4]],[5,5]],[6,6]]").unwrap()),
             1137
         );
         assert_eq!(
-            crate::magnitude(crate::parse_line(
-                "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"
-            )),
+            crate::magnitude(crate::parse_line("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").unwrap()),
             3488
         );
         assert_eq!(
-            crate::magnitude(crate::parse_line(
-                "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"
-            )),
+            crate::magnitude(
+                crate::parse_line("[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]").unwrap()
+            ),
             4140
         );
     }
@@ -405,43 +512,56 @@ mod tests {
     #[test]
     fn test_sample_list_sum_1() {
         let input = crate::lines_sum(
-            "[1,1]
+            &crate::parse(
+                "[1,1]
 [2,2]
 [3,3]
 [4,4]",
-        );
-        assert_eq!(input, crate::parse_line("[[[[1,1],[2,2]],[3,3]],[4,4]]"));
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(input, crate::parse_line("[[[[1,1],[2,2]],[3,3]],[4,4]]").unwrap());
     }
 
     #[test]
     fn test_sample_list_sum_2() {
         let input = crate::lines_sum(
-            "[1,1]
+            &crate::parse(
+                "[1,1]
 [2,2]
 [3,3]
 [4,4]
 [5,5]",
-        );
-        assert_eq!(input, crate::parse_line("[[[[3,0],[5,3]],[4,4]],[5,5]]"));
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(input, crate::parse_line("[[[[3,0],[5,3]],[4,4]],[5,5]]").unwrap());
     }
 
     #[test]
     fn test_sample_list_sum_3() {
         let input = crate::lines_sum(
-            "[1,1]
+            &crate::parse(
+                "[1,1]
 [2,2]
 [3,3]
 [4,4]
 [5,5]
 [6,6]",
-        );
-        assert_eq!(input, crate::parse_line("[[[[5,0],[7,4]],[5,5]],[6,6]]"));
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(input, crate::parse_line("[[[[5,0],[7,4]],[5,5]],[6,6]]").unwrap());
     }
 
     #[test]
     fn test_sample_list_sum_4() {
         let input = crate::lines_sum(
-            "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]
+            &crate::parse(
+                "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]
 [7,[[[3,7],[4,3]],[[6,3],[8,8]]]]
 [[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]
 [[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]
@@ -451,10 +571,13 @@ mod tests {
 [1,[[[9,3],9],[[9,0],[0,7]]]]
 [[[5,[7,4]],7],1]
 [[[[4,2],2],6],[8,7]]",
-        );
+            )
+            .unwrap(),
+        )
+        .unwrap();
         assert_eq!(
             input,
-            crate::parse_line("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]")
+            crate::parse_line("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").unwrap()
         );
     }
 
@@ -470,7 +593,7 @@ mod tests {
 [[9,3],[[9,9],[6,[4,9]]]]
 [[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
 [[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
-        assert_eq!(crate::part1(input), 4140);
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(4140));
     }
 
     #[test]
@@ -485,6 +608,36 @@ mod tests {
 [[9,3],[[9,9],[6,[4,9]]]]
 [[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
 [[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
-        assert_eq!(crate::part2(input), 3993);
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(3993));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            crate::parse("[1,2]\n[[1,2],3"),
+            Err(crate::PuzzleError::parse(
+                2,
+                9,
+                "",
+                "the line ends in the middle of a number"
+            ))
+        );
+        assert_eq!(
+            crate::parse("[1,2]\n[1;2]"),
+            Err(crate::PuzzleError::parse(2, 3, ";", "expected `,`"))
+        );
+        assert_eq!(
+            crate::parse("[[[[[[1,2],3],4],5],6],7]"),
+            Err(crate::PuzzleError::parse(
+                1,
+                6,
+                "[",
+                "pairs can't be nested inside more than 4 pairs"
+            ))
+        );
+        assert_eq!(
+            crate::part2(&crate::parse("[1,2]").unwrap()),
+            Err(crate::PuzzleError::no_solution("needs at least two numbers to add"))
+        );
     }
 }
@@ -0,0 +1,366 @@
+mod space;
+
+pub use space::{Point3, Rotation};
+
+use puzzle::PuzzleError;
+use std::collections::{HashMap, HashSet};
+
+/// Beacons two scanners must both see for their reports to be aligned.
+const OVERLAP: usize = 12;
+
+/// --- Day 19: Beacon Scanner ---
+/// Scanners report the beacons within 1000 units of them, relative to their own position and facing any of the 24
+/// orientations along the axes. Reports of scanners that share at least 12 beacons can be rotated and moved to line up;
+/// once every scanner is placed relative to scanner 0, how many beacons are there?
+///
+/// ```text example part1:79 part2:3621
+/// --- scanner 0 ---
+/// 404,-588,-901
+/// 528,-643,409
+/// -838,591,734
+/// 390,-675,-793
+/// -537,-823,-458
+/// -485,-357,347
+/// -345,-311,381
+/// -661,-816,-575
+/// -876,649,763
+/// -618,-824,-621
+/// 553,345,-567
+/// 474,580,667
+/// -447,-329,318
+/// -584,868,-557
+/// 544,-627,-890
+/// 564,392,-477
+/// 455,729,728
+/// -892,524,684
+/// -689,845,-530
+/// 423,-701,434
+/// 7,-33,-71
+/// 630,319,-379
+/// 443,580,662
+/// -789,900,-551
+/// 459,-707,401
+///
+/// --- scanner 1 ---
+/// 686,422,578
+/// 605,423,415
+/// 515,917,-361
+/// -336,658,858
+/// 95,138,22
+/// -476,619,847
+/// -340,-569,-846
+/// 567,-361,727
+/// -460,603,-452
+/// 669,-402,600
+/// 729,430,532
+/// -500,-761,534
+/// -322,571,750
+/// -466,-666,-811
+/// -429,-592,574
+/// -355,545,-477
+/// 703,-491,-529
+/// -328,-685,520
+/// 413,935,-424
+/// -391,539,-444
+/// 586,-435,557
+/// -364,-763,-893
+/// 807,-499,-711
+/// 755,-354,-619
+/// 553,889,-390
+///
+/// --- scanner 2 ---
+/// 649,640,665
+/// 682,-795,504
+/// -784,533,-524
+/// -644,584,-595
+/// -588,-843,648
+/// -30,6,44
+/// -674,560,763
+/// 500,723,-460
+/// 609,671,-379
+/// -555,-800,653
+/// -675,-892,-343
+/// 697,-426,-610
+/// 578,704,681
+/// 493,664,-388
+/// -671,-858,530
+/// -667,343,800
+/// 571,-461,-707
+/// -138,-166,112
+/// -889,563,-600
+/// 646,-828,498
+/// 640,759,510
+/// -630,509,768
+/// -681,-892,-333
+/// 673,-379,-804
+/// -742,-814,-386
+/// 577,-820,562
+///
+/// --- scanner 3 ---
+/// -589,542,597
+/// 605,-692,669
+/// -500,565,-823
+/// -660,373,557
+/// -458,-679,-417
+/// -488,449,543
+/// -626,468,-788
+/// 338,-750,-386
+/// 528,-832,-391
+/// 562,-778,733
+/// -938,-730,414
+/// 543,643,-506
+/// -524,371,-870
+/// 407,773,750
+/// -104,29,83
+/// 378,-903,-323
+/// -778,-728,485
+/// 426,699,580
+/// -438,-605,-362
+/// -469,-447,-387
+/// 509,732,623
+/// 647,635,-688
+/// -868,-804,481
+/// 614,-800,639
+/// 595,780,-596
+///
+/// --- scanner 4 ---
+/// 727,592,562
+/// -293,-554,779
+/// 441,611,-461
+/// -714,465,-776
+/// -743,427,-804
+/// -660,-479,-426
+/// 832,-632,460
+/// 927,-485,-438
+/// 408,393,-506
+/// 466,436,-512
+/// 110,16,151
+/// -258,-428,682
+/// -393,719,612
+/// -211,-452,876
+/// 808,-476,-593
+/// -575,615,604
+/// -485,667,467
+/// -680,325,-822
+/// -627,-443,-432
+/// 872,-547,-609
+/// 833,512,582
+/// 807,604,487
+/// 839,-516,451
+/// 891,-625,532
+/// -652,-548,-490
+/// 30,-46,-14
+/// ```
+pub fn part1(map: &Map) -> Result<usize, PuzzleError> {
+    Ok(map.beacons.len())
+}
+
+/// --- Part Two ---
+/// What is the largest Manhattan distance between any two scanners?
+pub fn part2(map: &Map) -> Result<u32, PuzzleError> {
+    Ok(map
+        .scanners
+        .iter()
+        .flat_map(|&a| map.scanners.iter().map(move |&b| a.manhattan_distance(b)))
+        .max()
+        .unwrap_or(0))
+}
+
+/// Scanners and beacons, all placed relative to the first scanner and facing the same way it does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Map {
+    /// Position of each scanner, in the order of the reports.
+    pub scanners: Vec<Point3>,
+    pub beacons: HashSet<Point3>,
+}
+
+/// Places every scanner by aligning its report with the report of a scanner already placed, starting from the first one.
+pub fn align(scanners: &[Vec<Point3>]) -> Result<Map, PuzzleError> {
+    if scanners.is_empty() {
+        return Err(PuzzleError::no_solution("there is no scanner"));
+    }
+
+    // Distances between beacons don't change with the orientation of the scanner, so reports that don't share enough
+    // of them can't overlap and aren't worth trying all the rotations on.
+    let fingerprints = scanners.iter().map(|beacons| fingerprint(beacons)).collect::<Vec<_>>();
+    let shared_pairs = OVERLAP * (OVERLAP - 1) / 2;
+
+    // Position and beacons of each scanner placed so far, relative to the first one
+    let mut placed: Vec<Option<(Point3, Vec<Point3>)>> = vec![None; scanners.len()];
+    placed[0] = Some((Point3::default(), scanners[0].clone()));
+    let mut to_align = vec![0];
+    while let Some(reference) = to_align.pop() {
+        for other in 0..scanners.len() {
+            if placed[other].is_some() || shared(&fingerprints[reference], &fingerprints[other]) < shared_pairs {
+                continue;
+            }
+            let (_, reference_beacons) = placed[reference].as_ref().unwrap();
+            if let Some((rotation, position)) = locate(reference_beacons, &scanners[other]) {
+                let beacons = scanners[other]
+                    .iter()
+                    .map(|&beacon| rotation.apply(beacon) + position)
+                    .collect();
+                placed[other] = Some((position, beacons));
+                to_align.push(other);
+            }
+        }
+    }
+
+    let mut map = Map {
+        scanners: Vec::new(),
+        beacons: HashSet::new(),
+    };
+    for (scanner, placed) in placed.into_iter().enumerate() {
+        let (position, beacons) = placed.ok_or_else(|| {
+            PuzzleError::no_solution(format!(
+                "scanner {} shares {} beacons with no other scanner",
+                scanner, OVERLAP
+            ))
+        })?;
+        map.scanners.push(position);
+        map.beacons.extend(beacons);
+    }
+    Ok(map)
+}
+
+/// How to turn `report` and where to move it so that at least [OVERLAP] of its beacons land on beacons of
+/// `reference`, if there is a way.
+fn locate(reference: &[Point3], report: &[Point3]) -> Option<(Rotation, Point3)> {
+    for rotation in Rotation::all() {
+        let mut offsets: HashMap<Point3, usize> = HashMap::new();
+        for &beacon in report {
+            let rotated = rotation.apply(beacon);
+            for &known in reference {
+                let count = offsets.entry(known - rotated).or_default();
+                *count += 1;
+                if *count == OVERLAP {
+                    return Some((rotation, known - rotated));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// How many times each squared distance between two beacons of a report comes up.
+fn fingerprint(beacons: &[Point3]) -> HashMap<i64, usize> {
+    let mut distances = HashMap::new();
+    for (i, &a) in beacons.iter().enumerate() {
+        for &b in &beacons[i + 1..] {
+            *distances.entry(a.squared_distance(b)).or_default() += 1;
+        }
+    }
+    distances
+}
+
+fn shared(a: &HashMap<i64, usize>, b: &HashMap<i64, usize>) -> usize {
+    a.iter()
+        .filter_map(|(distance, &count)| b.get(distance).map(|&other| count.min(other)))
+        .sum()
+}
+
+/// Reports of `--- scanner N ---` followed by a beacon per line as `x,y,z`, separated by blank lines.
+pub fn parse(input: &str) -> Result<Vec<Vec<Point3>>, PuzzleError> {
+    inpututils::sections(input.lines())
+        .iter()
+        .enumerate()
+        .map(|(scanner, section)| {
+            let header = format!("--- scanner {} ---", scanner);
+            if section.lines[0] != header {
+                let reason = format!("expected `{}`", header);
+                return Err(PuzzleError::parse(section.first_line, 1, section.lines[0], reason));
+            }
+            section
+                .numbered_lines()
+                .skip(1)
+                .map(|(line_number, line)| {
+                    match inpututils::parse_separated::<i32>(line_number, line, ',')?.as_slice() {
+                        &[x, y, z] => Ok(Point3::new(x, y, z)),
+                        _ => Err(PuzzleError::parse(line_number, 1, line, "expected `x,y,z`")),
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub struct Day19;
+
+impl puzzle::Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = u32;
+
+    // Both parts need the scanners aligned, which is most of the work
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        align(&parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Point3;
+    use puzzle::PuzzleError;
+
+    #[test]
+    fn test_part1() {
+        let map = crate::align(&crate::parse(include_str!("../../inputs/day19/example.txt")).unwrap()).unwrap();
+        assert_eq!(crate::part1(&map), Ok(79));
+    }
+
+    #[test]
+    fn test_part2() {
+        let map = crate::align(&crate::parse(include_str!("../../inputs/day19/example.txt")).unwrap()).unwrap();
+        assert_eq!(crate::part2(&map), Ok(3621));
+    }
+
+    #[test]
+    fn test_align() {
+        let map = crate::align(&crate::parse(include_str!("../../inputs/day19/example.txt")).unwrap()).unwrap();
+        assert_eq!(
+            map.scanners,
+            vec![
+                Point3::new(0, 0, 0),
+                Point3::new(68, -1246, -43),
+                Point3::new(1105, -1205, 1229),
+                Point3::new(-92, -2380, -20),
+                Point3::new(-20, -1133, 1061),
+            ]
+        );
+        assert!(map.beacons.contains(&Point3::new(-618, -824, -621)));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            crate::parse("--- scanner 0 ---\n1,2,3\n\n--- scanner 2 ---\n4,5,6"),
+            Err(PuzzleError::parse(
+                4,
+                1,
+                "--- scanner 2 ---",
+                "expected `--- scanner 1 ---`"
+            ))
+        );
+        assert_eq!(
+            crate::parse("--- scanner 0 ---\n1,2"),
+            Err(PuzzleError::parse(2, 1, "1,2", "expected `x,y,z`"))
+        );
+        let scanners = crate::parse("--- scanner 0 ---\n1,2,3\n\n--- scanner 1 ---\n4,5,6").unwrap();
+        assert_eq!(
+            crate::align(&scanners),
+            Err(PuzzleError::no_solution(
+                "scanner 1 shares 12 beacons with no other scanner"
+            ))
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use std::fmt::{self, Display};
+use std::ops::{Add, Sub};
+
+/// A position, or the offset between two, in 3D.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Point3 {
+    pub fn new(x: i32, y: i32, z: i32) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan_distance(self, other: Point3) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /// The square of the straight line distance, the same whichever way both points are rotated.
+    pub fn squared_distance(self, other: Point3) -> i64 {
+        let offset = self - other;
+        [offset.x, offset.y, offset.z]
+            .iter()
+            .map(|&axis| i64::from(axis) * i64::from(axis))
+            .sum()
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, other: Point3) -> Point3 {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, other: Point3) -> Point3 {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// A rotation by quarter turns around the axes, as the matrix turning a point.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rotation {
+    matrix: [[i32; 3]; 3],
+}
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation {
+        matrix: [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+    };
+
+    /// The 24 ways of facing along an axis with another axis up. They are the matrices with a single 1 or -1 in each row
+    /// and column that don't mirror, the other 24 of those being reflections.
+    pub fn all() -> Vec<Rotation> {
+        let permutations = [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]];
+        let mut rotations = Vec::new();
+        for permutation in permutations {
+            for signs in 0..8 {
+                let mut matrix = [[0; 3]; 3];
+                for (row, &column) in permutation.iter().enumerate() {
+                    matrix[row][column] = if signs & (1 << row) == 0 { 1 } else { -1 };
+                }
+                let rotation = Rotation { matrix };
+                if rotation.determinant() == 1 {
+                    rotations.push(rotation);
+                }
+            }
+        }
+        rotations
+    }
+
+    pub fn apply(&self, point: Point3) -> Point3 {
+        let [x, y, z] = self
+            .matrix
+            .map(|row| row[0] * point.x + row[1] * point.y + row[2] * point.z);
+        Point3::new(x, y, z)
+    }
+
+    /// `self` followed by `other`.
+    pub fn then(&self, other: &Rotation) -> Rotation {
+        let mut matrix = [[0; 3]; 3];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = (0..3).map(|k| other.matrix[i][k] * self.matrix[k][j]).sum();
+            }
+        }
+        Rotation { matrix }
+    }
+
+    fn determinant(&self) -> i32 {
+        let m = &self.matrix;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1]) - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::space::{Point3, Rotation};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_rotations() {
+        let rotations = Rotation::all();
+        assert_eq!(rotations.len(), 24);
+        assert!(rotations.contains(&Rotation::IDENTITY));
+
+        // Every rotation gives a different orientation of the same point, at the same distance from the origin
+        let point = Point3::new(1, 2, 3);
+        let rotated = rotations
+            .iter()
+            .map(|rotation| rotation.apply(point))
+            .collect::<HashSet<_>>();
+        assert_eq!(rotated.len(), 24);
+        assert!(rotated
+            .iter()
+            .all(|&rotated| rotated.squared_distance(Point3::default()) == 14));
+        assert!(rotated.contains(&Point3::new(-1, -2, 3)));
+        assert!(!rotated.contains(&Point3::new(-1, 2, 3)));
+
+        // Rotations compose into rotations
+        let quarter_turn = rotations
+            .iter()
+            .find(|rotation| rotation.apply(point) == Point3::new(-2, 1, 3))
+            .unwrap();
+        let half_turn = quarter_turn.then(quarter_turn);
+        assert_eq!(half_turn.apply(point), Point3::new(-1, -2, 3));
+        assert_eq!(half_turn.then(&half_turn), Rotation::IDENTITY);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point3::new(1105, -1205, 1229);
+        let b = Point3::new(-92, -2380, -20);
+        assert_eq!(a.manhattan_distance(b), 3621);
+        assert_eq!(a - b + b, a);
+        assert_eq!(a.to_string(), "1105,-1205,1229");
+    }
+}
@@ -0,0 +1,223 @@
+use grid::Grid;
+use puzzle::PuzzleError;
+
+/// Pixels of the enhancement algorithm, one for each value of a 3x3 square of pixels read as 9 bits.
+const ALGORITHM_LENGTH: usize = 512;
+
+/// --- Day 20: Trench Map ---
+/// The image enhancement algorithm turns every pixel of the image into the pixel at the index its 3x3 square of pixels
+/// reads as in binary, top left to bottom right with `#` as 1. The image is infinite: past the pixels given, every
+/// pixel is dark to start with, and they all change together. How many pixels are lit after enhancing the image twice?
+///
+/// ```text example part1:35 part2:3351
+/// ..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+///
+/// #..#.
+/// #....
+/// ##..#
+/// ..#..
+/// ..###
+/// ```
+pub fn part1(trench_map: &TrenchMap) -> Result<usize, PuzzleError> {
+    trench_map.enhanced(2).lit()
+}
+
+/// --- Part Two ---
+/// How many pixels are lit after enhancing the image 50 times?
+pub fn part2(trench_map: &TrenchMap) -> Result<usize, PuzzleError> {
+    trench_map.enhanced(50).lit()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrenchMap {
+    pub algorithm: Vec<bool>,
+    pub image: Image,
+}
+
+impl TrenchMap {
+    pub fn enhanced(&self, times: usize) -> Image {
+        (0..times).fold(self.image.clone(), |image, _| image.enhance(&self.algorithm))
+    }
+}
+
+/// An infinite image: the pixels of a grid, lit when `true`, and all around them pixels that are all the same.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub pixels: Grid<bool>,
+    pub background: bool,
+}
+
+impl Image {
+    /// The image with every pixel replaced according to the algorithm. The pixels next to the grid depend on it, so the
+    /// grid grows by one pixel on every side, and the background is replaced like any pixel surrounded by background.
+    pub fn enhance(&self, algorithm: &[bool]) -> Image {
+        let pixels = self.pixels.padded(1, self.background);
+        let enhanced = Grid::from_fn(pixels.width(), pixels.height(), |point| {
+            let index = SQUARE.iter().fold(0, |index, &offset| {
+                let lit = pixels
+                    .step(point, offset)
+                    .map_or(self.background, |pixel| pixels[pixel]);
+                index << 1 | usize::from(lit)
+            });
+            algorithm[index]
+        });
+        let background = if self.background {
+            algorithm[ALGORITHM_LENGTH - 1]
+        } else {
+            algorithm[0]
+        };
+        Image {
+            pixels: enhanced,
+            background,
+        }
+    }
+
+    /// How many pixels are lit, unless the background is, and there are infinitely many of them.
+    pub fn lit(&self) -> Result<usize, PuzzleError> {
+        if self.background {
+            return Err(PuzzleError::no_solution("infinitely many pixels are lit"));
+        }
+        Ok(self.pixels.iter().filter(|&&lit| lit).count())
+    }
+}
+
+/// The 3x3 square around a pixel, in the order its pixels are read.
+const SQUARE: [(isize, isize); 9] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (0, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+fn pixel(c: char) -> Option<bool> {
+    match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+/// The enhancement algorithm on the first line, then a blank line and the image.
+pub fn parse(input: &str) -> Result<TrenchMap, PuzzleError> {
+    let sections = inpututils::sections(input.lines());
+    let (algorithm_section, image_section) = match sections.as_slice() {
+        [algorithm_section, image_section] if algorithm_section.lines.len() == 1 => (algorithm_section, image_section),
+        _ => {
+            let line_number = sections.first().map_or(1, |section| section.first_line + 1);
+            return Err(PuzzleError::parse(
+                line_number,
+                1,
+                "",
+                "expected a blank line between the enhancement algorithm and the image",
+            ));
+        }
+    };
+
+    let line = algorithm_section.lines[0];
+    let algorithm = grid::parse_chars(algorithm_section.numbered_lines(), pixel, "`#` or `.`")?
+        .iter()
+        .copied()
+        .collect::<Vec<_>>();
+    if algorithm.len() != ALGORITHM_LENGTH {
+        let reason = format!("expected {} pixels in the enhancement algorithm", ALGORITHM_LENGTH);
+        return Err(PuzzleError::parse(algorithm_section.first_line, 1, line, reason));
+    }
+    let pixels = grid::parse_chars(image_section.numbered_lines(), pixel, "`#` or `.`")?;
+
+    Ok(TrenchMap {
+        algorithm,
+        image: Image {
+            pixels,
+            background: false,
+        },
+    })
+}
+
+pub struct Day20;
+
+impl puzzle::Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = TrenchMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzle::PuzzleError;
+
+    #[test]
+    fn test_part1() {
+        let trench_map = crate::parse(include_str!("../../inputs/day20/example.txt")).unwrap();
+        assert_eq!(crate::part1(&trench_map), Ok(35));
+    }
+
+    #[test]
+    fn test_part2() {
+        let trench_map = crate::parse(include_str!("../../inputs/day20/example.txt")).unwrap();
+        assert_eq!(crate::part2(&trench_map), Ok(3351));
+    }
+
+    #[test]
+    fn test_flashing_background() {
+        // All dark squares light up and all lit squares go dark, so the background flips at every step
+        let algorithm = format!("#{}.", ".".repeat(510));
+        let trench_map = crate::parse(&format!("{}\n\n#.\n..", algorithm)).unwrap();
+        let once = trench_map.enhanced(1);
+        assert!(once.background);
+        assert_eq!(
+            once.lit(),
+            Err(PuzzleError::no_solution("infinitely many pixels are lit"))
+        );
+        let twice = trench_map.enhanced(2);
+        assert!(!twice.background);
+        assert_eq!((twice.pixels.width(), twice.pixels.height()), (6, 6));
+        // Only the middle of the dark patch left by the lit pixel is surrounded by dark pixels
+        assert_eq!(twice.lit(), Ok(1));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            crate::parse("..#\n\n#."),
+            Err(PuzzleError::parse(
+                1,
+                1,
+                "..#",
+                "expected 512 pixels in the enhancement algorithm"
+            ))
+        );
+        let algorithm = ".".repeat(512);
+        assert_eq!(
+            crate::parse(&format!("{}\n\n#.\n.x", algorithm)),
+            Err(PuzzleError::parse(4, 2, "x", "expected `#` or `.`"))
+        );
+        assert_eq!(
+            crate::parse(&format!("{}\n#.", algorithm)),
+            Err(PuzzleError::parse(
+                2,
+                1,
+                "",
+                "expected a blank line between the enhancement algorithm and the image"
+            ))
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use puzzle::{parse_token, PuzzleError};
+use std::collections::HashMap;
+
+/// --- Day 21: Dirac Dice ---
+/// Two players move their pawn around a circular track of 10 spaces, marked 1 to 10, by the sum of three rolls of a
+/// die, and score the space they land on. The practice die rolls 1, 2, 3... up to 100 and back to 1, and the first
+/// player to reach 1000 wins. What do you get multiplying the score of the losing player by the number of rolls?
+///
+/// ```text example part1:739785 part2:444356092776315
+/// Player 1 starting position: 4
+/// Player 2 starting position: 8
+/// ```
+pub fn part1(starts: &[u32; 2]) -> Result<u64, PuzzleError> {
+    Ok(practice(*starts, PRACTICE))
+}
+
+/// --- Part Two ---
+/// The Dirac die has 3 faces, and every roll splits the universe in one universe for each of them. Playing to 21, in
+/// how many universes does the player who wins in the most universes win?
+pub fn part2(starts: &[u32; 2]) -> Result<u64, PuzzleError> {
+    Ok(count_wins(*starts, DIRAC).into_iter().max().unwrap())
+}
+
+/// The track and the score to reach.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    pub board_size: u32,
+    pub target: u32,
+}
+
+pub const PRACTICE: Rules = Rules {
+    board_size: 10,
+    target: 1000,
+};
+
+pub const DIRAC: Rules = Rules {
+    board_size: 10,
+    target: 21,
+};
+
+/// Rolls of a die each turn.
+const ROLLS: u32 = 3;
+
+fn advance(position: u32, moves: u32, rules: Rules) -> u32 {
+    (position - 1 + moves) % rules.board_size + 1
+}
+
+/// Plays with the deterministic 100-sided die until a player wins, and multiplies the score of the other one by the
+/// number of rolls.
+pub fn practice(starts: [u32; 2], rules: Rules) -> u64 {
+    let mut positions = starts;
+    let mut scores = [0; 2];
+    let mut rolls = 0;
+    for player in (0..2).cycle() {
+        let moves = (0..ROLLS).map(|i| (rolls + i) % 100 + 1).sum();
+        rolls += ROLLS;
+        positions[player] = advance(positions[player], moves, rules);
+        scores[player] += positions[player];
+        if scores[player] >= rules.target {
+            return u64::from(scores[1 - player]) * u64::from(rolls);
+        }
+    }
+    unreachable!("players take turns forever")
+}
+
+/// Each sum of three rolls of the Dirac die, with the number of universes it comes up in out of the 27.
+fn roll_sums() -> Vec<(u32, u64)> {
+    let mut sums: HashMap<u32, u64> = HashMap::new();
+    for a in 1..=3 {
+        for b in 1..=3 {
+            for c in 1..=3 {
+                *sums.entry(a + b + c).or_default() += 1;
+            }
+        }
+    }
+    let mut sums = sums.into_iter().collect::<Vec<_>>();
+    sums.sort();
+    sums
+}
+
+/// A game between two turns, from the side of the player about to play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    position: u32,
+    score: u32,
+    other_position: u32,
+    other_score: u32,
+}
+
+/// The number of universes in which each player wins with the Dirac die. Games that reach the same positions and scores
+/// play out the same, so each one is only counted once.
+pub fn count_wins(starts: [u32; 2], rules: Rules) -> [u64; 2] {
+    fn wins(state: State, rules: Rules, sums: &[(u32, u64)], known: &mut HashMap<State, [u64; 2]>) -> [u64; 2] {
+        if let Some(&wins) = known.get(&state) {
+            return wins;
+        }
+        // The wins of the player about to play, then of the other one
+        let mut total = [0, 0];
+        for &(sum, universes) in sums {
+            let position = advance(state.position, sum, rules);
+            let score = state.score + position;
+            if score >= rules.target {
+                total[0] += universes;
+            } else {
+                let next = State {
+                    position: state.other_position,
+                    score: state.other_score,
+                    other_position: position,
+                    other_score: score,
+                };
+                let [other_wins, own_wins] = wins(next, rules, sums, known);
+                total[0] += universes * own_wins;
+                total[1] += universes * other_wins;
+            }
+        }
+        known.insert(state, total);
+        total
+    }
+
+    let start = State {
+        position: starts[0],
+        score: 0,
+        other_position: starts[1],
+        other_score: 0,
+    };
+    wins(start, rules, &roll_sums(), &mut HashMap::new())
+}
+
+/// `Player N starting position: P` for both players.
+pub fn parse(input: &str) -> Result<[u32; 2], PuzzleError> {
+    let lines = inpututils::numbered_lines(input).collect::<Vec<_>>();
+    if lines.len() != 2 {
+        return Err(PuzzleError::parse(
+            lines.len().min(2) + 1,
+            1,
+            lines.get(2).map_or("", |&(_, line)| line),
+            "expected the starting positions of two players",
+        ));
+    }
+    let mut starts = [0; 2];
+    for (player, &(line_number, line)) in lines.iter().enumerate() {
+        let prefix = format!("Player {} starting position: ", player + 1);
+        let position = line
+            .strip_prefix(&prefix)
+            .ok_or_else(|| PuzzleError::parse(line_number, 1, line, format!("expected `{}<position>`", prefix)))?;
+        starts[player] = parse_token(line_number, line, position)?;
+        if !(1..=DIRAC.board_size).contains(&starts[player]) {
+            let reason = format!("expected a position from 1 to {}", DIRAC.board_size);
+            return Err(PuzzleError::parse(line_number, prefix.len() + 1, position, reason));
+        }
+    }
+    Ok(starts)
+}
+
+pub struct Day21;
+
+impl puzzle::Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = [u32; 2];
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Rules, DIRAC};
+    use puzzle::PuzzleError;
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../inputs/day21/example.txt");
+        assert_eq!(crate::part1(&crate::parse(input).unwrap()), Ok(739785));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../inputs/day21/example.txt");
+        assert_eq!(crate::part2(&crate::parse(input).unwrap()), Ok(444356092776315));
+    }
+
+    #[test]
+    fn test_count_wins() {
+        assert_eq!(crate::count_wins([4, 8], DIRAC), [444356092776315, 341960390180808]);
+        // Every roll gets the first player to 3 at least, out of reach of the second one
+        let rules = Rules {
+            board_size: 10,
+            target: 3,
+        };
+        assert_eq!(crate::count_wins([10, 10], rules), [27, 0]);
+        assert_eq!(crate::practice([10, 10], rules), 0);
+    }
+
+    #[test]
+    fn test_roll_sums() {
+        assert_eq!(
+            crate::roll_sums(),
+            vec![(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            crate::parse("Player 1 starting position: 4"),
+            Err(PuzzleError::parse(
+                2,
+                1,
+                "",
+                "expected the starting positions of two players"
+            ))
+        );
+        assert_eq!(
+            crate::parse("Player 1 starting position: 4\nPlayer 3 starting position: 8"),
+            Err(PuzzleError::parse(
+                2,
+                1,
+                "Player 3 starting position: 8",
+                "expected `Player 2 starting position: <position>`"
+            ))
+        );
+        assert_eq!(
+            crate::parse("Player 1 starting position: 11\nPlayer 2 starting position: 8"),
+            Err(PuzzleError::parse(1, 29, "11", "expected a position from 1 to 10"))
+        );
+    }
+}
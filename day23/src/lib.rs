@@ -0,0 +1,448 @@
+use graph::a_star;
+use puzzle::PuzzleError;
+use std::fmt::{self, Display};
+use Amphipod::{Amber, Bronze, Copper, Desert};
+
+const ROOMS: usize = 4;
+const HALLWAY_LENGTH: usize = 11;
+const MAX_DEPTH: usize = 4;
+
+/// The spaces of the hallway amphipods can stop at, all but the ones in front of a room.
+const STOPS: [usize; 7] = [0, 1, 3, 5, 7, 9, 10];
+
+/// The rows part 2 finds folded in the diagram, between the two rows of the rooms.
+const FOLDED: [[Amphipod; ROOMS]; 2] = [[Desert, Copper, Bronze, Amber], [Desert, Bronze, Amber, Copper]];
+
+/// --- Day 23: Amphipod ---
+/// Amphipods of 4 types, `A` to `D`, must each go to their own room, `A` the leftmost. Moving a space takes 1 energy
+/// for `A`, 10 for `B`, 100 for `C` and 1000 for `D`. They never stop in front of a room, only move into their own room
+/// once it holds no other type of amphipod, and once stopped in the hallway only move again into their room. What is
+/// the least energy needed to organize the amphipods?
+///
+/// ```text example part1:12521 part2:44169
+/// #############
+/// #...........#
+/// ###B#C#B#D###
+///   #A#D#C#A#
+///   #########
+/// ```
+pub fn part1(burrow: &Burrow) -> Result<usize, PuzzleError> {
+    burrow.organize()
+}
+
+/// --- Part Two ---
+/// Two more rows were folded in the diagram, between the first and second rows of the rooms:
+///
+/// ```text
+///   #D#C#B#A#
+///   #D#B#A#C#
+/// ```
+///
+/// What is the least energy needed to organize the amphipods, once they are unfolded?
+pub fn part2(burrow: &Burrow) -> Result<usize, PuzzleError> {
+    burrow
+        .unfolded()
+        .ok_or_else(|| PuzzleError::no_solution("expected rooms of 2 amphipods to unfold"))?
+        .organize()
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Amphipod {
+    Amber,
+    Bronze,
+    Copper,
+    Desert,
+}
+
+impl Amphipod {
+    fn from_char(c: char) -> Option<Amphipod> {
+        match c {
+            'A' => Some(Amber),
+            'B' => Some(Bronze),
+            'C' => Some(Copper),
+            'D' => Some(Desert),
+            _ => None,
+        }
+    }
+
+    fn to_char(self) -> char {
+        (b'A' + self as u8) as char
+    }
+
+    /// Energy taken to move one space.
+    pub fn energy(self) -> usize {
+        10usize.pow(self as u32)
+    }
+
+    /// The room the amphipod belongs in, from the left.
+    pub fn room(self) -> usize {
+        self as usize
+    }
+}
+
+/// The space of the hallway in front of a room.
+fn door(room: usize) -> usize {
+    2 + 2 * room
+}
+
+/// Where every amphipod is. Rooms are `depth` spaces deep, listed from the one next to the hallway down; they are arrays
+/// rather than vectors so that a burrow is cheap to copy and hash while searching.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Burrow {
+    hallway: [Option<Amphipod>; HALLWAY_LENGTH],
+    rooms: [[Option<Amphipod>; MAX_DEPTH]; ROOMS],
+    depth: usize,
+}
+
+impl Burrow {
+    /// The least energy needed to get every amphipod to its room.
+    pub fn organize(&self) -> Result<usize, PuzzleError> {
+        a_star(*self, Burrow::moves, Burrow::min_energy_left, Burrow::is_organized)
+            .map(|path| path.cost)
+            .ok_or_else(|| PuzzleError::no_solution("the amphipods can't be organized"))
+    }
+
+    /// The burrow with the rows of [FOLDED] inserted between the two rows of the rooms, if there are two.
+    pub fn unfolded(&self) -> Option<Burrow> {
+        if self.depth != 2 {
+            return None;
+        }
+        let mut unfolded = *self;
+        unfolded.depth = 4;
+        for (unfolded, room) in unfolded.rooms.iter_mut().zip(self.rooms) {
+            *unfolded = [room[0], None, None, room[1]];
+        }
+        for (row, folded) in FOLDED.iter().enumerate() {
+            for (room, &amphipod) in folded.iter().enumerate() {
+                unfolded.rooms[room][row + 1] = Some(amphipod);
+            }
+        }
+        Some(unfolded)
+    }
+
+    pub fn is_organized(&self) -> bool {
+        (0..ROOMS).all(|room| {
+            self.room(room)
+                .iter()
+                .all(|&space| space.is_some_and(|amphipod| amphipod.room() == room))
+        })
+    }
+
+    /// Every burrow a single amphipod can get to by moving, with the energy it takes.
+    pub fn moves(&self) -> Vec<(Burrow, usize)> {
+        let mut moves = Vec::new();
+
+        // From the hallway into their room, as deep as they can go
+        for (position, amphipod) in self.hallway_amphipods() {
+            let room = amphipod.room();
+            if !self.is_ready(room) || !self.is_clear(position, door(room)) {
+                continue;
+            }
+            if let Some(space) = self.room(room).iter().rposition(Option::is_none) {
+                let mut next = *self;
+                next.hallway[position] = None;
+                next.rooms[room][space] = Some(amphipod);
+                moves.push((next, (position.abs_diff(door(room)) + space + 1) * amphipod.energy()));
+            }
+        }
+
+        // Out of a room that holds other types of amphipods, to wherever they can stop in the hallway. Going straight
+        // into another room costs the same as stopping in the hallway between both rooms on the way.
+        for room in 0..ROOMS {
+            if self.is_ready(room) {
+                continue;
+            }
+            let Some(space) = self.room(room).iter().position(Option::is_some) else {
+                continue;
+            };
+            let amphipod = self.rooms[room][space].unwrap();
+            for position in STOPS {
+                if self.is_clear(door(room), position) {
+                    let mut next = *self;
+                    next.rooms[room][space] = None;
+                    next.hallway[position] = Some(amphipod);
+                    moves.push((next, (space + 1 + door(room).abs_diff(position)) * amphipod.energy()));
+                }
+            }
+        }
+        moves
+    }
+
+    /// A lower bound of the energy left to spend, for [a_star] to look at the most promising burrows first: every
+    /// amphipod goes straight to its room, those in the way of others below them leaving it and coming back.
+    fn min_energy_left(&self) -> usize {
+        let hallway = self
+            .hallway_amphipods()
+            .map(|(position, amphipod)| (position.abs_diff(door(amphipod.room())) + 1) * amphipod.energy());
+        let rooms = (0..ROOMS).flat_map(|room| {
+            let settled = self
+                .room(room)
+                .iter()
+                .rev()
+                .take_while(|&&space| space.is_some_and(|amphipod| amphipod.room() == room))
+                .count();
+            self.room(room)[..self.depth - settled]
+                .iter()
+                .enumerate()
+                .filter_map(move |(space, &amphipod)| {
+                    let amphipod = amphipod?;
+                    // Leaving the room, stepping aside at least one space, going to the room and back in
+                    let across = door(room).abs_diff(door(amphipod.room())).max(2);
+                    Some((space + 1 + across + 1) * amphipod.energy())
+                })
+        });
+        hallway.chain(rooms).sum()
+    }
+
+    fn room(&self, room: usize) -> &[Option<Amphipod>] {
+        &self.rooms[room][..self.depth]
+    }
+
+    /// Whether amphipods can go into the room, holding none of another type.
+    fn is_ready(&self, room: usize) -> bool {
+        self.room(room)
+            .iter()
+            .all(|&space| space.is_none_or(|amphipod| amphipod.room() == room))
+    }
+
+    /// Whether the hallway is free from `from`, not included, to `to`.
+    fn is_clear(&self, from: usize, to: usize) -> bool {
+        let spaces = if from < to { from + 1..to + 1 } else { to..from };
+        self.hallway[spaces].iter().all(Option::is_none)
+    }
+
+    fn hallway_amphipods(&self) -> impl Iterator<Item = (usize, Amphipod)> + '_ {
+        self.hallway
+            .iter()
+            .enumerate()
+            .filter_map(|(position, &space)| Some((position, space?)))
+    }
+}
+
+fn space_char(space: Option<Amphipod>) -> char {
+    space.map_or('.', Amphipod::to_char)
+}
+
+/// The diagram the burrow is parsed from.
+impl Display for Burrow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", "#".repeat(HALLWAY_LENGTH + 2))?;
+        writeln!(
+            f,
+            "#{}#",
+            self.hallway.iter().map(|&space| space_char(space)).collect::<String>()
+        )?;
+        for row in 0..self.depth {
+            let spaces = (0..ROOMS)
+                .map(|room| space_char(self.rooms[room][row]).to_string())
+                .collect::<Vec<_>>()
+                .join("#");
+            let wall = if row == 0 { "##" } else { "  " };
+            writeln!(f, "{}#{}#{}", wall, spaces, wall.trim_end())?;
+        }
+        write!(f, "  {}", "#".repeat(2 * ROOMS + 1))
+    }
+}
+
+/// The diagram of the burrow, with rooms 1 to 4 spaces deep.
+pub fn parse(input: &str) -> Result<Burrow, PuzzleError> {
+    let lines = inpututils::numbered_lines(input)
+        .map(|(line_number, line)| (line_number, line.trim_end()))
+        .collect::<Vec<_>>();
+    let depth = lines.len().saturating_sub(3);
+    if !(1..=MAX_DEPTH).contains(&depth) {
+        return Err(PuzzleError::parse(
+            lines.len().min(3 + MAX_DEPTH) + 1,
+            1,
+            lines.get(3 + MAX_DEPTH).map_or("", |&(_, line)| line),
+            format!("expected a hallway and 1 to {} rows of rooms", MAX_DEPTH),
+        ));
+    }
+
+    let mut burrow = Burrow {
+        hallway: [None; HALLWAY_LENGTH],
+        rooms: [[None; MAX_DEPTH]; ROOMS],
+        depth,
+    };
+    let empty = Burrow {
+        hallway: [None; HALLWAY_LENGTH],
+        ..burrow
+    }
+    .to_string();
+    for (&(line_number, line), expected) in lines.iter().zip(empty.lines()) {
+        if line.len() != expected.len() {
+            return Err(PuzzleError::parse(
+                line_number,
+                1,
+                line,
+                format!("expected `{}`", expected),
+            ));
+        }
+        for (column, (c, wall)) in line.chars().zip(expected.chars()).enumerate() {
+            if wall != '.' {
+                if c != wall {
+                    return Err(PuzzleError::parse(
+                        line_number,
+                        1,
+                        line,
+                        format!("expected `{}`", expected),
+                    ));
+                }
+                continue;
+            }
+            let space = match c {
+                '.' => None,
+                _ => Some(Amphipod::from_char(c).ok_or_else(|| {
+                    PuzzleError::parse(
+                        line_number,
+                        column + 1,
+                        c,
+                        "expected `.` or an amphipod from `A` to `D`",
+                    )
+                })?),
+            };
+            if line_number == lines[0].0 + 1 {
+                burrow.hallway[column - 1] = space;
+            } else {
+                let row = line_number - lines[0].0 - 2;
+                burrow.rooms[(column - 3) / 2][row] = space;
+            }
+        }
+    }
+
+    let amphipods = burrow
+        .hallway
+        .iter()
+        .chain((0..ROOMS).flat_map(|room| burrow.room(room)))
+        .flatten()
+        .collect::<Vec<_>>();
+    for amphipod in [Amber, Bronze, Copper, Desert] {
+        if amphipods.iter().filter(|&&&other| other == amphipod).count() != depth {
+            return Err(PuzzleError::no_solution(format!(
+                "expected {} amphipods of each type, as many as the spaces of a room",
+                depth
+            )));
+        }
+    }
+    Ok(burrow)
+}
+
+pub struct Day23;
+
+impl puzzle::Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Burrow;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzle::PuzzleError;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            crate::part1(&crate::parse(include_str!("../../inputs/day23/example.txt")).unwrap()),
+            Ok(12521)
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            crate::part2(&crate::parse(include_str!("../../inputs/day23/example.txt")).unwrap()),
+            Ok(44169)
+        );
+    }
+
+    #[test]
+    fn test_unfolded() {
+        let example = include_str!("../../inputs/day23/example.txt");
+        let burrow = crate::parse(example).unwrap();
+        assert_eq!(burrow.to_string(), example.trim_end());
+        let unfolded = burrow.unfolded().unwrap();
+        assert_eq!(
+            unfolded.to_string(),
+            "#############
+#...........#
+###B#C#B#D###
+  #D#C#B#A#
+  #D#B#A#C#
+  #A#D#C#A#
+  #########"
+        );
+        assert_eq!(crate::parse(&unfolded.to_string()), Ok(unfolded));
+        assert_eq!(unfolded.unfolded(), None);
+    }
+
+    #[test]
+    fn test_moves() {
+        // Only the moves into a room are left, `A` and `B` each on top of the other amphipod of their type
+        let burrow = crate::parse(
+            "#############
+#.A.......B.#
+###.#.#C#D###
+  #A#B#C#D#
+  #########",
+        )
+        .unwrap();
+        assert!(!burrow.is_organized());
+        let moves = burrow.moves();
+        assert_eq!(moves.len(), 2);
+        assert!(moves.iter().any(|&(_, energy)| energy == 2));
+        assert!(moves.iter().any(|&(_, energy)| energy == 60));
+        assert_eq!(burrow.organize(), Ok(2 + 60));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            crate::parse("#############\n#...........#"),
+            Err(PuzzleError::parse(
+                3,
+                1,
+                "",
+                "expected a hallway and 1 to 4 rows of rooms"
+            ))
+        );
+        assert_eq!(
+            crate::parse("#############\n#...........#\n###B#C#B#D###\n  #A#D#C#A\n  #########"),
+            Err(PuzzleError::parse(4, 1, "  #A#D#C#A", "expected `  #.#.#.#.#`"))
+        );
+        assert_eq!(
+            crate::parse("#############\n#...........#\n###B#C#B#D###\n  #A#E#C#A#\n  #########"),
+            Err(PuzzleError::parse(
+                4,
+                6,
+                "E",
+                "expected `.` or an amphipod from `A` to `D`"
+            ))
+        );
+        assert_eq!(
+            crate::parse("#############\n#...........#\n###B#C#B#D###\n  #A#D#C#C#\n  #########"),
+            Err(PuzzleError::no_solution(
+                "expected 2 amphipods of each type, as many as the spaces of a room"
+            ))
+        );
+        // Both amphipods need to get past each other
+        let burrow = crate::parse("#############\n#.....D.A...#\n###.#B#C#.###\n  #A#B#C#D#\n  #########").unwrap();
+        assert_eq!(
+            burrow.organize(),
+            Err(PuzzleError::no_solution("the amphipods can't be organized"))
+        );
+    }
+}
@@ -0,0 +1,99 @@
+mod search;
+mod shortest;
+
+pub use search::{all_paths, bfs, count_paths};
+pub use shortest::{a_star, dijkstra, dijkstra_all, Distances};
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Edges between nodes, each with a cost. Searches take the successors of a node as a closure, so they work as well on
+/// graphs that are never built, like the cells of a grid; this is for the ones that are, like a list of connections.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph<N: Eq + Hash> {
+    edges: HashMap<N, Vec<(N, usize)>>,
+}
+
+impl<N: Eq + Hash + Clone> Graph<N> {
+    pub fn new() -> Graph<N> {
+        Graph { edges: HashMap::new() }
+    }
+
+    /// An edge from `from` to `to`. A connection listed twice is still a single way, which costs the cheaper of the two.
+    pub fn add_edge(&mut self, from: N, to: N, cost: usize) {
+        self.edges.entry(to.clone()).or_default();
+        let edges = self.edges.entry(from).or_default();
+        match edges.iter_mut().find(|(node, _)| *node == to) {
+            Some((_, existing)) => *existing = cost.min(*existing),
+            None => edges.push((to, cost)),
+        }
+    }
+
+    /// An edge both ways, with the same cost.
+    pub fn connect(&mut self, a: N, b: N, cost: usize) {
+        self.add_edge(a.clone(), b.clone(), cost);
+        self.add_edge(b, a, cost);
+    }
+
+    /// The edges going out of `node`, none if it isn't in the graph. Like with a map, the node can be looked up by any
+    /// form it borrows as, like a `&str` for a `String`.
+    pub fn edges<Q: ?Sized + Hash + Eq>(&self, node: &Q) -> &[(N, usize)]
+    where
+        N: Borrow<Q>,
+    {
+        self.edges.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn neighbours<Q: ?Sized + Hash + Eq>(&self, node: &Q) -> impl Iterator<Item = &N>
+    where
+        N: Borrow<Q>,
+    {
+        self.edges(node).iter().map(|(neighbour, _)| neighbour)
+    }
+
+    /// Every node with an edge to or from it.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.edges.keys()
+    }
+}
+
+impl<N: Eq + Hash + Clone> Default for Graph<N> {
+    fn default() -> Graph<N> {
+        Graph::new()
+    }
+}
+
+/// A path found by a search, from its start to its end, and what it costs to follow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    pub nodes: Vec<N>,
+    pub cost: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Graph;
+
+    #[test]
+    fn test_graph() {
+        let mut graph = Graph::new();
+        graph.connect("start", "A", 1);
+        graph.add_edge("A", "end", 3);
+        assert_eq!(graph.edges(&"A"), &[("start", 1), ("end", 3)]);
+        assert_eq!(graph.neighbours(&"end").count(), 0);
+        assert_eq!(graph.edges(&"b"), &[]);
+        assert_eq!(graph.nodes().count(), 3);
+
+        graph.connect("end", "A", 2);
+        graph.add_edge("start", "A", 5);
+        assert_eq!(graph.edges(&"A"), &[("start", 1), ("end", 2)]);
+        assert_eq!(graph.edges(&"start"), &[("A", 1)]);
+        assert_eq!(graph.edges(&"end"), &[("A", 2)]);
+
+        let mut owned = Graph::new();
+        owned.connect("start".to_string(), "A".to_string(), 1);
+        assert_eq!(owned.edges("start"), &[("A".to_string(), 1)]);
+        assert_eq!(owned.neighbours("A").collect::<Vec<_>>(), vec!["start"]);
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/// The path from `start` to a node `is_goal` accepts with the fewest steps, if there is one. `successors` gives the
+/// nodes a node has an edge to.
+pub fn bfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I, mut is_goal: impl FnMut(&N) -> bool) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    // The node each node was first reached from, none for the start
+    let mut previous: HashMap<N, Option<N>> = HashMap::new();
+    let mut to_visit = VecDeque::new();
+    previous.insert(start.clone(), None);
+    to_visit.push_back(start);
+
+    while let Some(node) = to_visit.pop_front() {
+        if is_goal(&node) {
+            let mut path = vec![node];
+            while let Some(Some(node)) = previous.get(path.last().unwrap()) {
+                path.push(node.clone());
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for successor in successors(&node) {
+            if !previous.contains_key(&successor) {
+                previous.insert(successor.clone(), Some(node.clone()));
+                to_visit.push_back(successor);
+            }
+        }
+    }
+    None
+}
+
+/// Counts the paths from `start` to the nodes `is_goal` accepts. A path only goes on to a successor `can_enter` allows,
+/// given the path so far, which is what keeps it from going around in circles. Paths stop at the first goal they reach.
+pub fn count_paths<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut can_enter: impl FnMut(&[N], &N) -> bool,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> usize
+where
+    N: Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut count = 0;
+    dfs(
+        &mut vec![start],
+        &mut successors,
+        &mut can_enter,
+        &mut is_goal,
+        &mut |_| count += 1,
+    );
+    count
+}
+
+/// Same as [count_paths], with the paths themselves.
+pub fn all_paths<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut can_enter: impl FnMut(&[N], &N) -> bool,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Vec<Vec<N>>
+where
+    N: Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Vec::new();
+    dfs(
+        &mut vec![start],
+        &mut successors,
+        &mut can_enter,
+        &mut is_goal,
+        &mut |path: &[N]| paths.push(path.to_vec()),
+    );
+    paths
+}
+
+fn dfs<N, I>(
+    path: &mut Vec<N>,
+    successors: &mut impl FnMut(&N) -> I,
+    can_enter: &mut impl FnMut(&[N], &N) -> bool,
+    is_goal: &mut impl FnMut(&N) -> bool,
+    found: &mut impl FnMut(&[N]),
+) where
+    N: Clone,
+    I: IntoIterator<Item = N>,
+{
+    for successor in successors(path.last().unwrap()) {
+        if !can_enter(path, &successor) {
+            continue;
+        }
+        let reached_goal = is_goal(&successor);
+        path.push(successor);
+        if reached_goal {
+            found(path);
+        } else {
+            dfs(path, successors, can_enter, is_goal, found);
+        }
+        path.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{all_paths, bfs, count_paths, Graph};
+
+    #[test]
+    fn test_bfs() {
+        let successors = |&n: &u32| [n + 1, n * 2];
+        assert_eq!(bfs(1, successors, |&n| n == 10), Some(vec![1, 2, 4, 5, 10]));
+        assert_eq!(bfs(1, successors, |&n| n == 1), Some(vec![1]));
+        assert_eq!(bfs(1, |_: &u32| None, |&n| n == 2), None);
+    }
+
+    #[test]
+    fn test_paths() {
+        let mut graph = Graph::new();
+        // `b-A` repeats `A-b`, which mustn't count the paths through it twice
+        for (a, b) in [
+            ("start", "A"),
+            ("start", "b"),
+            ("A", "b"),
+            ("A", "end"),
+            ("b", "end"),
+            ("b", "A"),
+        ] {
+            graph.connect(a, b, 1);
+        }
+        let successors = |cave: &&'static str| graph.neighbours(cave).copied().collect::<Vec<_>>();
+        let once = |path: &[&str], cave: &&str| !path.contains(cave);
+        let mut paths = all_paths("start", successors, once, |&cave| cave == "end");
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                vec!["start", "A", "b", "end"],
+                vec!["start", "A", "end"],
+                vec!["start", "b", "A", "end"],
+                vec!["start", "b", "end"],
+            ]
+        );
+        assert_eq!(count_paths("start", successors, once, |&cave| cave == "end"), 4);
+        assert_eq!(count_paths("start", successors, once, |&cave| cave == "nowhere"), 0);
+    }
+}
@@ -0,0 +1,219 @@
+use crate::Path;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+/// The cheapest path from `start` to a node `is_goal` accepts, if there is one. `successors` gives the nodes a node has
+/// an edge to, each with the cost of that edge.
+pub fn dijkstra<N, I>(start: N, successors: impl FnMut(&N) -> I, is_goal: impl FnMut(&N) -> bool) -> Option<Path<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    a_star(start, successors, |_| 0, is_goal)
+}
+
+/// Same as [dijkstra], looking at the nodes that seem closer to a goal first. `heuristic` estimates the cost from a node
+/// to the closest goal. A node is never looked at again once it is, so the path found is only the cheapest if the
+/// heuristic is consistent: it never drops by more than the cost of an edge, and is 0 on a goal. The Manhattan distance
+/// on a grid where every step costs at least 1 is.
+pub fn a_star<N, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> usize,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let (best, goal) = search(start, successors, heuristic, is_goal);
+    Distances { best }.path_to(&goal?)
+}
+
+/// The cost of the cheapest path from `start` to every node it can reach.
+pub fn dijkstra_all<N, I>(start: N, successors: impl FnMut(&N) -> I) -> Distances<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let (best, _) = search(start, successors, |_| 0, |_| false);
+    Distances { best }
+}
+
+/// The node each node is reached from on its cheapest path, none for the start, and the cost of that path.
+type Best<N> = HashMap<N, (Option<N>, usize)>;
+
+/// The cheapest paths from a start to the nodes it can reach.
+#[derive(Debug, Clone)]
+pub struct Distances<N> {
+    best: Best<N>,
+}
+
+impl<N: Eq + Hash + Clone> Distances<N> {
+    /// The cost of the cheapest path to `node`, if it can be reached.
+    pub fn cost(&self, node: &N) -> Option<usize> {
+        self.best.get(node).map(|&(_, cost)| cost)
+    }
+
+    /// The cheapest path to `node`, if it can be reached.
+    pub fn path_to(&self, node: &N) -> Option<Path<N>> {
+        let cost = self.cost(node)?;
+        let mut nodes = vec![node.clone()];
+        while let Some((Some(previous), _)) = self.best.get(nodes.last().unwrap()) {
+            nodes.push(previous.clone());
+        }
+        nodes.reverse();
+        Some(Path { nodes, cost })
+    }
+
+    /// The nodes that can be reached, the start included.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.best.keys()
+    }
+}
+
+/// Visits the nodes cheapest first, or cheapest estimate first with a `heuristic`, until one is a goal. Returns the best
+/// known way to every node seen, and the goal found.
+fn search<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (Best<N>, Option<N>)
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut best = HashMap::new();
+    let mut visited = HashSet::new();
+    let mut to_visit = BinaryHeap::new();
+
+    best.insert(start.clone(), (None, 0));
+    to_visit.push(Visit {
+        estimate: heuristic(&start),
+        cost: 0,
+        node: start,
+    });
+
+    while let Some(Visit { node, cost, .. }) = to_visit.pop() {
+        if !visited.insert(node.clone()) {
+            // Already visited this node
+            continue;
+        }
+        if is_goal(&node) {
+            return (best, Some(node));
+        }
+
+        for (successor, step) in successors(&node) {
+            let new_cost = cost + step;
+            let is_cheaper = best.get(&successor).is_none_or(|&(_, current)| new_cost < current);
+
+            if is_cheaper {
+                best.insert(successor.clone(), (Some(node.clone()), new_cost));
+                to_visit.push(Visit {
+                    estimate: new_cost + heuristic(&successor),
+                    cost: new_cost,
+                    node: successor,
+                });
+            }
+        }
+    }
+
+    (best, None)
+}
+
+/// A node waiting to be visited, the one with the lowest estimated total cost coming out of the heap first.
+#[derive(Debug)]
+struct Visit<N> {
+    estimate: usize,
+    cost: usize,
+    node: N,
+}
+
+impl<N> Ord for Visit<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl<N> PartialOrd for Visit<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> PartialEq for Visit<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate.eq(&other.estimate)
+    }
+}
+
+impl<N> Eq for Visit<N> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{a_star, dijkstra, dijkstra_all, Graph, Path};
+
+    fn graph() -> Graph<char> {
+        let mut graph = Graph::new();
+        graph.connect('a', 'b', 7);
+        graph.connect('a', 'c', 9);
+        graph.connect('a', 'f', 14);
+        graph.connect('b', 'c', 10);
+        graph.connect('b', 'd', 15);
+        graph.connect('c', 'd', 11);
+        graph.connect('c', 'f', 2);
+        graph.connect('d', 'e', 6);
+        graph.connect('e', 'f', 9);
+        graph.add_edge('x', 'a', 1);
+        graph
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let graph = graph();
+        let successors = |node: &char| graph.edges(node).to_vec();
+        assert_eq!(
+            dijkstra('a', successors, |&node| node == 'e'),
+            Some(Path {
+                nodes: vec!['a', 'c', 'f', 'e'],
+                cost: 20
+            })
+        );
+        assert_eq!(
+            dijkstra('a', successors, |&node| node == 'a'),
+            Some(Path {
+                nodes: vec!['a'],
+                cost: 0
+            })
+        );
+        assert_eq!(dijkstra('a', successors, |&node| node == 'x'), None);
+
+        let distances = dijkstra_all('a', successors);
+        assert_eq!(distances.cost(&'d'), Some(20));
+        assert_eq!(distances.path_to(&'d').unwrap().nodes, vec!['a', 'c', 'd']);
+        assert_eq!(distances.cost(&'x'), None);
+        assert_eq!(distances.nodes().count(), 6);
+    }
+
+    #[test]
+    fn test_a_star() {
+        // Around a wall on a 5x5 grid, from the top left corner to the top right one
+        let wall = [(2, 0), (2, 1), (2, 2), (2, 3)];
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y) && !wall.contains(&(x, y)))
+                .map(|point| (point, 1))
+        };
+        let manhattan = |&(x, y): &(i32, i32)| ((4 - x).abs() + y.abs()) as usize;
+        let path = a_star((0, 0), successors, manhattan, |&point| point == (4, 0)).unwrap();
+        assert_eq!(path.cost, 12);
+        assert_eq!(path.nodes.len(), 13);
+        assert_eq!(
+            dijkstra((0, 0), successors, |&point| point == (4, 0)).map(|path| path.cost),
+            Some(12)
+        );
+    }
+}
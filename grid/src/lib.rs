@@ -0,0 +1,354 @@
+mod parse;
+
+pub use parse::{parse_chars, parse_digits};
+
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+/// Offsets to the 4 neighbours of a cell: up, left, right and down.
+pub const NEIGHBOURS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets to the 8 neighbours of a cell, diagonals included, row by row.
+pub const NEIGHBOURS8: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+/// A cell of a grid: `x` is the column, `y` the row, both from the top left corner.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Point {
+        Point { x, y }
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// A rectangle of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+    wrapping: bool,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `width` by `height` cells, all set to `cell`.
+    pub fn new(width: usize, height: usize, cell: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(width, height, |_| cell.clone())
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Point) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+            .map(&mut cell)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+            wrapping: false,
+        }
+    }
+
+    /// # Panics
+    /// If the rows aren't all of the same length. Parsers check it first, to point at the line that isn't.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "the rows of a grid must all be {} cells long",
+            width
+        );
+        Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+            wrapping: false,
+        }
+    }
+
+    /// The same grid, with its edges wrapping around: stepping off one side comes back on the other side, so every cell
+    /// has all its neighbours, unless a side shorter than 3 makes some of them the same cell.
+    pub fn wrapping(mut self) -> Grid<T> {
+        self.wrapping = true;
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.y * self.width + point.x])
+        } else {
+            None
+        }
+    }
+
+    /// The bottom right corner, if the grid isn't empty.
+    pub fn last_point(&self) -> Option<Point> {
+        (!self.is_empty()).then(|| Point::new(self.width - 1, self.height - 1))
+    }
+
+    /// Every point of the grid, row by row. The grid isn't borrowed, so cells can be changed along the way.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// The cells, row by row.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// The cells with their point, row by row.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// # Panics
+    /// If there is no row `y`.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// # Panics
+    /// If there is no column `x`.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "no column {} in a grid {} cells wide", x, self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` doesn't take 0, and a grid without columns has no cells to show anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The point `offset` away from `point`, if it is on the grid. On a wrapping grid it always is.
+    pub fn step(&self, point: Point, offset: (isize, isize)) -> Option<Point> {
+        step(self.width, self.height, self.wrapping, point, offset)
+    }
+
+    /// The points up, left, right and down of `point` that are on the grid, each once and never `point` itself. The grid
+    /// isn't borrowed, so cells can be changed along the way.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> {
+        self.neighbours(point, &NEIGHBOURS4)
+    }
+
+    /// The points around `point` that are on the grid, diagonals included, each once and never `point` itself. The grid
+    /// isn't borrowed, so cells can be changed along the way.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> {
+        self.neighbours(point, &NEIGHBOURS8)
+    }
+
+    fn neighbours(&self, point: Point, offsets: &'static [(isize, isize)]) -> impl Iterator<Item = Point> {
+        let (width, height, wrapping) = (self.width, self.height, self.wrapping);
+        offsets.iter().enumerate().filter_map(move |(i, &offset)| {
+            let neighbour = step(width, height, wrapping, point, offset)?;
+            // Wrapping around a side shorter than 3 comes back on a neighbour already given, or on the point itself
+            let repeated = wrapping
+                && (neighbour == point
+                    || offsets[..i]
+                        .iter()
+                        .any(|&earlier| step(width, height, wrapping, point, earlier) == Some(neighbour)));
+            (!repeated).then_some(neighbour)
+        })
+    }
+
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(cell).collect(),
+            wrapping: self.wrapping,
+        }
+    }
+
+    /// A grid `across` times wider and `down` times taller, made of copies of this one. `cell` makes each cell of the
+    /// copies from the original one and the position of the copy, `(0, 0)` being the top left one.
+    pub fn tiled(&self, across: usize, down: usize, cell: impl Fn(&T, Point) -> T) -> Grid<T> {
+        Grid::from_fn(self.width * across, self.height * down, |point| {
+            let tile = Point::new(point.x / self.width, point.y / self.height);
+            cell(&self[Point::new(point.x % self.width, point.y % self.height)], tile)
+        })
+        .with_wrapping(self.wrapping)
+    }
+
+    /// A grid `across` times wider and `down` times taller, made of copies of this one.
+    pub fn repeated(&self, across: usize, down: usize) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.tiled(across, down, |cell, _| cell.clone())
+    }
+
+    /// The grid grown by `margin` cells in every direction, the new cells set to `cell`. What was at `(x, y)` ends up at
+    /// `(x + margin, y + margin)`.
+    pub fn padded(&self, margin: usize, cell: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width + 2 * margin, self.height + 2 * margin, |point| {
+            match (point.x.checked_sub(margin), point.y.checked_sub(margin)) {
+                (Some(x), Some(y)) => self.get(Point::new(x, y)).unwrap_or(&cell).clone(),
+                _ => cell.clone(),
+            }
+        })
+        .with_wrapping(self.wrapping)
+    }
+
+    /// The grid as text, a line per row and a character per cell.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn with_wrapping(mut self, wrapping: bool) -> Grid<T> {
+        self.wrapping = wrapping;
+        self
+    }
+}
+
+fn step(width: usize, height: usize, wrapping: bool, point: Point, (dx, dy): (isize, isize)) -> Option<Point> {
+    let x = point.x as isize + dx;
+    let y = point.y as isize + dy;
+    if wrapping && width > 0 && height > 0 {
+        Some(Point::new(
+            x.rem_euclid(width as isize) as usize,
+            y.rem_euclid(height as isize) as usize,
+        ))
+    } else if (0..width as isize).contains(&x) && (0..height as isize).contains(&y) {
+        Some(Point::new(x as usize, y as usize))
+    } else {
+        None
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{} is outside of a {}x{} grid", point, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{} is outside of a {}x{} grid", point, width, height))
+    }
+}
+
+/// A line per row, the cells of a row next to each other.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Grid, Point};
+
+    fn grid() -> Grid<u32> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])
+    }
+
+    #[test]
+    fn test_access() {
+        let mut grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 0)], 3);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        grid[Point::new(0, 1)] = 7;
+        assert_eq!(grid.row(1), &[7, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.last_point(), Some(Point::new(2, 1)));
+        assert_eq!(
+            grid.enumerate().find(|&(_, &cell)| cell == 5),
+            Some((Point::new(1, 1), &5))
+        );
+        assert_eq!(grid.map(|cell| cell * 2).iter().sum::<u32>(), 48);
+        assert_eq!(Grid::new(2, 1, 'a').to_string(), "aa");
+        assert!(Grid::<u32>::from_rows(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours8(Point::new(1, 0)).count(), 5);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 5);
+
+        let grid = grid.wrapping();
+        assert_eq!(
+            grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(),
+            vec![Point::new(0, 1), Point::new(2, 0), Point::new(1, 0)]
+        );
+        assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 5);
+        assert_eq!(Grid::new(1, 1, 0).wrapping().neighbours8(Point::new(0, 0)).count(), 0);
+        assert_eq!(grid.step(Point::new(2, 1), (1, 1)), Some(Point::new(0, 0)));
+    }
+
+    #[test]
+    fn test_tiled() {
+        let grid = grid();
+        assert_eq!(grid.repeated(2, 1).to_string(), "123123\n456456");
+        assert_eq!(
+            grid.tiled(1, 2, |&cell, tile| cell + tile.y as u32).to_string(),
+            "123\n456\n234\n567"
+        );
+        assert_eq!(grid.render(|&cell| if cell % 2 == 0 { '#' } else { '.' }), ".#.\n#.#");
+        assert_eq!(grid.padded(1, 0).to_string(), "00000\n01230\n04560\n00000");
+        assert_eq!(grid.padded(0, 0), grid);
+    }
+}
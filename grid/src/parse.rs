@@ -0,0 +1,59 @@
+use crate::Grid;
+use puzzle::PuzzleError;
+
+/// Rows of single digits like `2199943210`, all of the same length.
+pub fn parse_digits(input: &str) -> Result<Grid<u32>, PuzzleError> {
+    inpututils::digit_grid(inpututils::numbered_lines(input)).map(Grid::from_rows)
+}
+
+/// Rows of characters all of the same length, like `#.##..`, each turned into a cell by `cell`. Characters `cell` has no
+/// cell for are errors, saying what was `expected` instead. Lines come numbered, so a grid can be a section of an input.
+pub fn parse_chars<'a, T>(
+    lines: impl IntoIterator<Item = (usize, &'a str)>,
+    cell: impl Fn(char) -> Option<T>,
+    expected: &str,
+) -> Result<Grid<T>, PuzzleError> {
+    inpututils::char_grid(lines, cell, expected).map(Grid::from_rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Grid;
+    use puzzle::PuzzleError;
+
+    #[test]
+    fn test_parse_digits() {
+        assert_eq!(
+            crate::parse_digits("219\n398"),
+            Ok(Grid::from_rows(vec![vec![2, 1, 9], vec![3, 9, 8]]))
+        );
+        assert_eq!(
+            crate::parse_digits("219\n3a8"),
+            Err(PuzzleError::parse(2, 2, "a", "expected a digit"))
+        );
+    }
+
+    #[test]
+    fn test_parse_chars() {
+        let cell = |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        };
+        let grid = crate::parse_chars(inpututils::numbered_lines("#.\n.#"), cell, "`#` or `.`").unwrap();
+        assert_eq!(grid.render(|&lit| if lit { '#' } else { '.' }), "#.\n.#");
+        assert_eq!(
+            crate::parse_chars(inpututils::numbered_lines("#.\n.x"), cell, "`#` or `.`"),
+            Err(PuzzleError::parse(2, 2, "x", "expected `#` or `.`"))
+        );
+        assert_eq!(
+            crate::parse_chars(inpututils::numbered_lines("#.\n."), cell, "`#` or `.`"),
+            Err(PuzzleError::parse(
+                2,
+                1,
+                ".",
+                "expected 2 characters like the first line"
+            ))
+        );
+    }
+}
@@ -0,0 +1,435 @@
+//! One generator per day. Each returns the input without a trailing newline, the way inputs are normalized.
+
+use crate::Rng;
+use std::collections::HashSet;
+
+/// Depths drifting downwards like the sea floor, kept between the surface and 60000.
+pub fn day01(rng: &mut Rng, size: usize) -> String {
+    let mut depth = rng.range(100..=200) as i64;
+    let mut depths = Vec::with_capacity(size);
+    for _ in 0..size {
+        depths.push(depth.to_string());
+        depth = (depth + rng.range(0..=20) as i64 - 8).clamp(0, 60_000);
+    }
+    depths.join("\n")
+}
+
+/// Moves that keep the submarine near the surface. The answers overflow day 2's `i32` past a few thousand commands.
+pub fn day02(rng: &mut Rng, size: usize) -> String {
+    let mut depth = 0;
+    let mut commands = Vec::with_capacity(size);
+    for _ in 0..size {
+        let amount = rng.range(1..=9);
+        let command = if rng.chance(34) {
+            "forward"
+        } else if depth >= amount + 20 && rng.chance(50) {
+            "up"
+        } else {
+            "down"
+        };
+        match command {
+            "up" => depth -= amount,
+            "down" => depth += amount,
+            _ => {}
+        }
+        commands.push(format!("{} {}", command, amount));
+    }
+    commands.join("\n")
+}
+
+/// Distinct numbers, so the bit criteria of part 2 always end with a single one.
+pub fn day03(rng: &mut Rng, size: usize) -> String {
+    let size = size.min(1 << 30);
+    let width = (usize::BITS - size.leading_zeros() + 1).clamp(12, 31);
+    let mut numbers = HashSet::new();
+    let mut report = Vec::with_capacity(size);
+    while report.len() < size {
+        let number = rng.range(0..=(1 << width) - 1);
+        if numbers.insert(number) {
+            report.push(format!("{:0width$b}", number, width = width as usize));
+        }
+    }
+    report.join("\n")
+}
+
+/// A hundred boards of `size`×`size` distinct numbers. Every number is drawn, so every board wins.
+pub fn day04(rng: &mut Rng, size: usize) -> String {
+    let cells = size * size;
+    let mut pool = (0..(2 * cells).max(100) as u64).collect::<Vec<_>>();
+    let width = (pool.len() - 1).to_string().len();
+    let mut draws = pool.clone();
+    rng.shuffle(&mut draws);
+    let draws = draws.iter().map(u64::to_string).collect::<Vec<_>>();
+
+    let mut sections = vec![draws.join(",")];
+    for _ in 0..100 {
+        rng.shuffle(&mut pool);
+        let rows = pool[..cells]
+            .chunks(size)
+            .map(|row| {
+                row.iter()
+                    .map(|number| format!("{:>width$}", number, width = width))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>();
+        sections.push(rows.join("\n"));
+    }
+    sections.join("\n\n")
+}
+
+/// Horizontal, vertical and diagonal lines on a 1000×1000 floor.
+pub fn day05(rng: &mut Rng, size: usize) -> String {
+    let mut lines = Vec::with_capacity(size);
+    while lines.len() < size {
+        let (x1, y1) = (rng.range(0..=999) as i64, rng.range(0..=999) as i64);
+        let (dx, dy) = *rng.pick(&[(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (1, -1), (-1, 1), (-1, -1)]);
+        let length = rng.range(1..=200) as i64;
+        let (x2, y2) = (x1 + dx * length, y1 + dy * length);
+        if (0..1000).contains(&x2) && (0..1000).contains(&y2) {
+            lines.push(format!("{},{} -> {},{}", x1, y1, x2, y2));
+        }
+    }
+    lines.join("\n")
+}
+
+/// Timers from 1 to 5, like the real school of lanternfish.
+pub fn day06(rng: &mut Rng, size: usize) -> String {
+    let timers = (0..size).map(|_| rng.range(1..=5).to_string()).collect::<Vec<_>>();
+    timers.join(",")
+}
+
+pub fn day07(rng: &mut Rng, size: usize) -> String {
+    let positions = (0..size)
+        .map(|_| rng.range(0..=(2 * size as u64).max(16)).to_string())
+        .collect::<Vec<_>>();
+    positions.join(",")
+}
+
+const SEGMENTS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+fn scramble(rng: &mut Rng, wiring: &[char], digit: usize) -> String {
+    let mut wires = SEGMENTS[digit]
+        .chars()
+        .map(|segment| wiring[(segment as u8 - b'a') as usize])
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut wires);
+    wires.into_iter().collect()
+}
+
+/// Each entry has its own wiring of the segments, and letters in any order.
+pub fn day08(rng: &mut Rng, size: usize) -> String {
+    let mut entries = Vec::with_capacity(size);
+    for _ in 0..size {
+        let mut wiring = "abcdefg".chars().collect::<Vec<_>>();
+        rng.shuffle(&mut wiring);
+        let mut patterns = (0..10).map(|digit| scramble(rng, &wiring, digit)).collect::<Vec<_>>();
+        rng.shuffle(&mut patterns);
+        let output = (0..4)
+            .map(|_| {
+                let digit = rng.below(10);
+                scramble(rng, &wiring, digit)
+            })
+            .collect::<Vec<_>>();
+        entries.push(format!("{} | {}", patterns.join(" "), output.join(" ")));
+    }
+    entries.join("\n")
+}
+
+fn digits(rng: &mut Rng, side: usize, digit: impl Fn(&mut Rng) -> u64) -> String {
+    let rows = (0..side)
+        .map(|_| (0..side).map(|_| digit(rng).to_string()).collect::<String>())
+        .collect::<Vec<_>>();
+    rows.join("\n")
+}
+
+/// Basins walled in by a quarter of 9s.
+pub fn day09(rng: &mut Rng, size: usize) -> String {
+    digits(rng, size, |rng| if rng.chance(25) { 9 } else { rng.range(0..=8) })
+}
+
+/// Half of the lines corrupted, the other half incomplete.
+pub fn day10(rng: &mut Rng, size: usize) -> String {
+    const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+    let mut lines = Vec::with_capacity(size);
+    for _ in 0..size {
+        let length = rng.range(20..=110);
+        let corrupted_at = rng.chance(50).then(|| rng.range(1..=length - 1));
+        let mut open: Vec<(char, char)> = Vec::new();
+        let mut line = String::new();
+        for position in 0..length {
+            if let (Some(at), Some(&(_, expected))) = (corrupted_at, open.last()) {
+                if position >= at {
+                    let wrong = PAIRS.iter().map(|&(_, close)| close).filter(|&close| close != expected);
+                    line.push(*rng.pick(&wrong.collect::<Vec<_>>()));
+                    break;
+                }
+            }
+            if open.is_empty() || rng.chance(55) {
+                let pair = *rng.pick(&PAIRS);
+                line.push(pair.0);
+                open.push(pair);
+            } else {
+                line.push(open.pop().unwrap().1);
+            }
+        }
+        if open.is_empty() {
+            line.push('(');
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+/// Random energy levels that flash all at once within a thousand steps.
+pub fn day11(rng: &mut Rng, size: usize) -> String {
+    // Random grids don't always end up flashing all at once, and the larger the grid the less likely it is: start over
+    // with energy levels closer to each other until it does, down to all the same which flash together right away.
+    for spread in (0..=9).rev() {
+        for _ in 0..10 {
+            let grid = digits(rng, size, |rng| rng.range(0..=spread));
+            if synchronizes(&grid, 1000) {
+                return grid;
+            }
+        }
+    }
+    unreachable!("a grid of a single energy level flashes all at once")
+}
+
+/// Whether every octopus of the grid flashes in the same step within `steps` steps.
+fn synchronizes(grid: &str, steps: usize) -> bool {
+    let mut levels = grid
+        .lines()
+        .map(|line| line.bytes().map(|digit| digit - b'0').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let side = levels.len();
+    for _ in 0..steps {
+        let mut flashing = Vec::new();
+        for (y, row) in levels.iter_mut().enumerate() {
+            for (x, level) in row.iter_mut().enumerate() {
+                *level += 1;
+                if *level == 10 {
+                    flashing.push((x, y));
+                }
+            }
+        }
+        let mut flashes = 0;
+        while let Some((x, y)) = flashing.pop() {
+            flashes += 1;
+            // Adjacent octopuses, and the flashing one itself which is already past 9
+            let around = |i: usize| i.saturating_sub(1)..(i + 2).min(side);
+            for (row, ny) in levels[around(y)].iter_mut().zip(around(y)) {
+                for (level, nx) in row[around(x)].iter_mut().zip(around(x)) {
+                    *level += 1;
+                    if *level == 10 {
+                        flashing.push((nx, ny));
+                    }
+                }
+            }
+        }
+        for level in levels.iter_mut().flatten().filter(|level| **level > 9) {
+            *level = 0;
+        }
+        if flashes == side * side {
+            return true;
+        }
+    }
+    false
+}
+
+/// A connected cave system where no two big caves are next to each other, so there are finitely many paths. The number
+/// of paths grows very fast with the size.
+pub fn day12(rng: &mut Rng, size: usize) -> String {
+    let mut names = HashSet::new();
+    let mut name = |rng: &mut Rng, big: bool| loop {
+        let name = (0..2)
+            .map(|_| (b'a' + rng.range(0..=25) as u8) as char)
+            .collect::<String>();
+        let name = if big { name.to_uppercase() } else { name };
+        if names.insert(name.clone()) {
+            return name;
+        }
+    };
+    let mut caves = (0..size).map(|_| name(rng, false)).collect::<Vec<_>>();
+    caves.extend((0..size.div_ceil(3)).map(|_| name(rng, true)));
+    rng.shuffle(&mut caves[1..]);
+    let is_big = |cave: &str| cave.chars().all(|c| c.is_ascii_uppercase());
+
+    let mut edges: Vec<(String, String)> = Vec::new();
+    let mut connect = |a: &str, b: &str| {
+        let exists = edges.iter().any(|(x, y)| (x == a && y == b) || (x == b && y == a));
+        if a != b && !(is_big(a) && is_big(b)) && !exists {
+            edges.push((a.to_string(), b.to_string()));
+        }
+    };
+    // A tree first, so everything is reachable, then a few more passages
+    for i in 1..caves.len() {
+        let mut other = rng.below(i);
+        if is_big(&caves[i]) && is_big(&caves[other]) {
+            other = 0;
+        }
+        connect(&caves[i], &caves[other]);
+    }
+    for _ in 0..size / 2 {
+        connect(rng.pick(&caves).as_str(), rng.pick(&caves).as_str());
+    }
+    for _ in 0..rng.range(1..=2) {
+        connect("start", rng.pick(&caves).as_str());
+        connect(rng.pick(&caves).as_str(), "end");
+    }
+    rng.shuffle(&mut edges);
+    let lines = edges.iter().map(|(a, b)| format!("{}-{}", a, b)).collect::<Vec<_>>();
+    lines.join("\n")
+}
+
+/// A sheet folded down to 40×6 like the real one, with no dot ever landing on a fold line.
+pub fn day13(rng: &mut Rng, size: usize) -> String {
+    let (mut width, mut height) = (40, 6);
+    let (mut x_folds, mut y_folds) = (Vec::new(), Vec::new());
+    for _ in 0..5 {
+        x_folds.push(width);
+        width = 2 * width + 1;
+    }
+    for _ in 0..7 {
+        y_folds.push(height);
+        height = 2 * height + 1;
+    }
+    // The biggest folds come first, alternating while both axes have some left
+    let mut folds = Vec::new();
+    while !x_folds.is_empty() || !y_folds.is_empty() {
+        if let Some(x) = x_folds.pop() {
+            folds.push(('x', x));
+        }
+        if let Some(y) = y_folds.pop() {
+            folds.push(('y', y));
+        }
+    }
+
+    let lands_on_a_fold = |mut x: u64, mut y: u64| {
+        folds.iter().any(|&(axis, line)| {
+            let coordinate = if axis == 'x' { &mut x } else { &mut y };
+            if *coordinate > line {
+                *coordinate = 2 * line - *coordinate;
+            }
+            *coordinate == line
+        })
+    };
+    let mut dots = HashSet::new();
+    let mut lines = Vec::new();
+    while dots.len() < size.min(5000) {
+        let (x, y) = (rng.range(0..=width - 1), rng.range(0..=height - 1));
+        if !lands_on_a_fold(x, y) && dots.insert((x, y)) {
+            lines.push(format!("{},{}", x, y));
+        }
+    }
+    let folds = folds
+        .iter()
+        .map(|(axis, line)| format!("fold along {}={}", axis, line))
+        .collect::<Vec<_>>();
+    format!("{}\n\n{}", lines.join("\n"), folds.join("\n"))
+}
+
+/// Ten elements like the real input, with a rule for every pair.
+pub fn day14(rng: &mut Rng, size: usize) -> String {
+    let elements = "BCFHKNOPSV".chars().collect::<Vec<_>>();
+    let template = (0..size).map(|_| *rng.pick(&elements)).collect::<String>();
+    let mut rules = Vec::new();
+    for &a in &elements {
+        for &b in &elements {
+            rules.push(format!("{}{} -> {}", a, b, rng.pick(&elements)));
+        }
+    }
+    rng.shuffle(&mut rules);
+    format!("{}\n\n{}", template, rules.join("\n"))
+}
+
+pub fn day15(rng: &mut Rng, size: usize) -> String {
+    digits(rng, size, |rng| rng.range(1..=9))
+}
+
+/// A transmission whose packets are nested `size` deep. Only one sub-packet of each operator goes deeper, so the
+/// transmission grows linearly, and products only multiply small values so nothing overflows.
+pub fn day16(rng: &mut Rng, size: usize) -> String {
+    fn literal(rng: &mut Rng, bits: &mut String, max: u64) {
+        bits.push_str(&format!("{:03b}100", rng.range(0..=7)));
+        let value = rng.range(0..=max);
+        let groups = (64 - value.leading_zeros()).max(1).div_ceil(4);
+        for group in (0..groups).rev() {
+            let prefix = if group == 0 { '0' } else { '1' };
+            bits.push_str(&format!("{}{:04b}", prefix, (value >> (4 * group)) & 0xF));
+        }
+    }
+
+    fn packet(rng: &mut Rng, bits: &mut String, depth: usize, comparison_only: bool) {
+        if depth == 0 {
+            return literal(rng, bits, if comparison_only { 15 } else { 1 << 16 });
+        }
+        let type_id = if comparison_only {
+            rng.range(5..=7)
+        } else {
+            *rng.pick(&[0, 1, 2, 3, 5, 6, 7])
+        };
+        let count = if type_id >= 5 { 2 } else { rng.range(1..=3) as usize };
+        let deeper = rng.below(count);
+        let mut sub_packets = String::new();
+        for i in 0..count {
+            match (i == deeper, type_id) {
+                // A product only goes deeper through a comparison, worth 0 or 1
+                (true, 1) => packet(rng, &mut sub_packets, depth - 1, true),
+                (true, _) => packet(rng, &mut sub_packets, depth - 1, false),
+                (false, 1) => literal(rng, &mut sub_packets, 15),
+                (false, _) => literal(rng, &mut sub_packets, 1 << 16),
+            }
+        }
+        bits.push_str(&format!("{:03b}{:03b}", rng.range(0..=7), type_id));
+        if sub_packets.len() < 1 << 15 && rng.chance(50) {
+            bits.push_str(&format!("0{:015b}", sub_packets.len()));
+        } else {
+            bits.push_str(&format!("1{:011b}", count));
+        }
+        bits.push_str(&sub_packets);
+    }
+
+    let mut bits = String::new();
+    packet(rng, &mut bits, size, false);
+    while !bits.len().is_multiple_of(4) {
+        bits.push('0');
+    }
+    bits.as_bytes()
+        .chunks(4)
+        .map(|nibble| {
+            let nibble = u32::from_str_radix(std::str::from_utf8(nibble).unwrap(), 2).unwrap();
+            std::char::from_digit(nibble, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect()
+}
+
+/// A target area about `size` away to the right and below.
+pub fn day17(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(4) as i64;
+    let x1 = rng.range(size as u64..=2 * size as u64) as i64;
+    let x2 = x1 + rng.range((size / 3) as u64..=(size / 2) as u64) as i64;
+    let y2 = -(rng.range((size / 2) as u64..=size as u64) as i64);
+    let y1 = y2 - rng.range((size / 3) as u64..=(size / 2) as u64) as i64;
+    format!("target area: x={}..{}, y={}..{}", x1, x2, y1, y2)
+}
+
+/// Reduced numbers: pairs nested at most 4 deep, regular numbers up to 9.
+pub fn day18(rng: &mut Rng, size: usize) -> String {
+    fn element(rng: &mut Rng, depth: usize) -> String {
+        if depth == 4 || rng.chance(40) {
+            rng.range(0..=9).to_string()
+        } else {
+            pair(rng, depth + 1)
+        }
+    }
+
+    fn pair(rng: &mut Rng, depth: usize) -> String {
+        format!("[{},{}]", element(rng, depth), element(rng, depth))
+    }
+
+    let numbers = (0..size).map(|_| pair(rng, 1)).collect::<Vec<_>>();
+    numbers.join("\n")
+}
@@ -0,0 +1,167 @@
+mod days;
+mod rng;
+
+pub use rng::Rng;
+
+/// Produces valid puzzle inputs for a day, as large as asked, the same ones for the same seed.
+pub struct Generator {
+    pub day: u8,
+    /// What the size stands for, e.g. `lines` or `grid side`.
+    pub size: &'static str,
+    /// The size of the real puzzle inputs.
+    pub default_size: usize,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        (self.generate)(&mut Rng::new(seed), size.max(1))
+    }
+}
+
+/// A generator for each of the days 1 to 18, in calendar order. The later days have none yet, so they can't be
+/// generated or cross-checked.
+pub static GENERATORS: &[Generator] = &[
+    Generator {
+        day: 1,
+        size: "depth measurements",
+        default_size: 2000,
+        generate: days::day01,
+    },
+    Generator {
+        day: 2,
+        size: "commands",
+        default_size: 1000,
+        generate: days::day02,
+    },
+    Generator {
+        day: 3,
+        size: "binary numbers",
+        default_size: 1000,
+        generate: days::day03,
+    },
+    Generator {
+        day: 4,
+        size: "side of the boards",
+        default_size: 5,
+        generate: days::day04,
+    },
+    Generator {
+        day: 5,
+        size: "lines of vents",
+        default_size: 500,
+        generate: days::day05,
+    },
+    Generator {
+        day: 6,
+        size: "lanternfish",
+        default_size: 300,
+        generate: days::day06,
+    },
+    Generator {
+        day: 7,
+        size: "crabs",
+        default_size: 1000,
+        generate: days::day07,
+    },
+    Generator {
+        day: 8,
+        size: "entries",
+        default_size: 200,
+        generate: days::day08,
+    },
+    Generator {
+        day: 9,
+        size: "side of the heightmap",
+        default_size: 100,
+        generate: days::day09,
+    },
+    Generator {
+        day: 10,
+        size: "lines",
+        default_size: 100,
+        generate: days::day10,
+    },
+    Generator {
+        day: 11,
+        size: "side of the grid",
+        default_size: 10,
+        generate: days::day11,
+    },
+    Generator {
+        day: 12,
+        size: "small caves",
+        default_size: 6,
+        generate: days::day12,
+    },
+    Generator {
+        day: 13,
+        size: "dots",
+        default_size: 800,
+        generate: days::day13,
+    },
+    Generator {
+        day: 14,
+        size: "length of the template",
+        default_size: 20,
+        generate: days::day14,
+    },
+    Generator {
+        day: 15,
+        size: "side of the risk map",
+        default_size: 100,
+        generate: days::day15,
+    },
+    Generator {
+        day: 16,
+        size: "nesting depth",
+        default_size: 50,
+        generate: days::day16,
+    },
+    Generator {
+        day: 17,
+        size: "distance to the target",
+        default_size: 100,
+        generate: days::day17,
+    },
+    Generator {
+        day: 18,
+        size: "snailfish numbers",
+        default_size: 100,
+        generate: days::day18,
+    },
+];
+
+pub fn find_generator(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_generators_are_in_order() {
+        let days = crate::GENERATORS
+            .iter()
+            .map(|generator| generator.day)
+            .collect::<Vec<_>>();
+        assert_eq!(days, (1..=18).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_same_seed_same_input() {
+        for generator in crate::GENERATORS {
+            assert_eq!(
+                generator.generate(5, 42),
+                generator.generate(5, 42),
+                "day {}",
+                generator.day
+            );
+            assert_ne!(
+                generator.generate(5, 42),
+                generator.generate(5, 43),
+                "day {}",
+                generator.day
+            );
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::ops::RangeInclusive;
+
+/// Small seeded pseudo-random generator (SplitMix64). Its own implementation, so a seed gives the same input on every
+/// platform and with every version of the dependencies.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number within `range`, both ends included.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = (*range.start(), *range.end());
+        assert!(start <= end, "empty range {}..={}", start, end);
+        match (end - start).checked_add(1) {
+            Some(length) => start + self.next_u64() % length,
+            None => self.next_u64(),
+        }
+    }
+
+    /// An index below `n`, which must not be 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        self.range(0..=n as u64 - 1) as usize
+    }
+
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.range(0..=99) < percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Rng;
+
+    #[test]
+    fn test_seeds_are_reproducible() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.range(1..=6)).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        assert!(numbers(7).iter().all(|number| (1..=6).contains(number)));
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut rng = Rng::new(1);
+        let mut items = (0..20).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,63 @@
+use puzzle::{column_of, parse_token, PuzzleError};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Parses an `x,y` pair. `token` must be a slice of `line`, so errors point at the right column.
+pub fn coordinate<T: FromStr>(line_number: usize, line: &str, token: &str) -> Result<(T, T), PuzzleError>
+where
+    T::Err: Display,
+{
+    let (x, y) = token
+        .split_once(',')
+        .filter(|(_, y)| !y.contains(','))
+        .ok_or_else(|| PuzzleError::parse(line_number, column_of(line, token), token, "expected `x,y`"))?;
+    Ok((
+        parse_token(line_number, line, x.trim())?,
+        parse_token(line_number, line, y.trim())?,
+    ))
+}
+
+/// One `x,y` pair per line.
+pub fn coordinates<'a, T: FromStr>(
+    lines: impl IntoIterator<Item = (usize, &'a str)>,
+) -> Result<Vec<(T, T)>, PuzzleError>
+where
+    T::Err: Display,
+{
+    lines
+        .into_iter()
+        .map(|(line_number, line)| coordinate(line_number, line, line))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzle::PuzzleError;
+
+    #[test]
+    fn test_coordinate() {
+        let line = "0,9 -> 5,9";
+        assert_eq!(crate::coordinate::<i32>(1, line, &line[7..]), Ok((5, 9)));
+        assert_eq!(crate::coordinate::<i32>(1, line, &line[..3]), Ok((0, 9)));
+        assert_eq!(
+            crate::coordinate::<i32>(1, "0;9 -> 5,9", &"0;9 -> 5,9"[..3]),
+            Err(PuzzleError::parse(1, 1, "0;9", "expected `x,y`"))
+        );
+        assert_eq!(
+            crate::coordinate::<i32>(1, "0,9,1", "0,9,1"),
+            Err(PuzzleError::parse(1, 1, "0,9,1", "expected `x,y`"))
+        );
+    }
+
+    #[test]
+    fn test_coordinates() {
+        assert_eq!(
+            crate::coordinates::<usize>(crate::numbered_lines("6,10\n0,14")),
+            Ok(vec![(6, 10), (0, 14)])
+        );
+        assert_eq!(
+            crate::coordinates::<usize>(crate::numbered_lines("6,10\n0,x4")),
+            Err(PuzzleError::parse(2, 3, "x4", "invalid digit found in string"))
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use puzzle::PuzzleError;
+use std::fmt::{self, Display};
+use std::io;
+
+/// Why an input could not be loaded from a file or a reader.
+#[derive(Debug)]
+pub enum InputError {
+    Io(io::Error),
+    Parse(PuzzleError),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Io(error) => write!(f, "{}", error),
+            InputError::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Io(error) => Some(error),
+            InputError::Parse(error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for InputError {
+    fn from(error: io::Error) -> InputError {
+        InputError::Io(error)
+    }
+}
+
+impl From<PuzzleError> for InputError {
+    fn from(error: PuzzleError) -> InputError {
+        InputError::Parse(error)
+    }
+}
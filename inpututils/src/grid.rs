@@ -0,0 +1,59 @@
+use puzzle::PuzzleError;
+
+/// Rows of single digits like `2199943210`, all of the same length.
+pub fn digit_grid<'a>(lines: impl IntoIterator<Item = (usize, &'a str)>) -> Result<Vec<Vec<u32>>, PuzzleError> {
+    char_grid(lines, |c| c.to_digit(10), "a digit")
+}
+
+/// Rows of characters all of the same length, like `#.##..`, each turned into a cell by `cell`. Characters `cell` has no
+/// cell for are errors, saying what was `expected` instead. Lines come numbered, so a grid can be a section of an input.
+pub fn char_grid<'a, T>(
+    lines: impl IntoIterator<Item = (usize, &'a str)>,
+    cell: impl Fn(char) -> Option<T>,
+    expected: &str,
+) -> Result<Vec<Vec<T>>, PuzzleError> {
+    let mut grid: Vec<Vec<T>> = Vec::new();
+    for (line_number, line) in lines {
+        let row = line
+            .chars()
+            .enumerate()
+            .map(|(j, c)| {
+                cell(c).ok_or_else(|| PuzzleError::parse(line_number, j + 1, c, format!("expected {}", expected)))
+            })
+            .collect::<Result<Vec<T>, PuzzleError>>()?;
+        if let Some(first_row) = grid.first() {
+            if row.len() != first_row.len() {
+                let reason = format!("expected {} characters like the first line", first_row.len());
+                return Err(PuzzleError::parse(line_number, 1, line, reason));
+            }
+        }
+        grid.push(row);
+    }
+    Ok(grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use puzzle::PuzzleError;
+
+    #[test]
+    fn test_digit_grid() {
+        assert_eq!(
+            crate::digit_grid(crate::numbered_lines("219\n398")),
+            Ok(vec![vec![2, 1, 9], vec![3, 9, 8]])
+        );
+        assert_eq!(
+            crate::digit_grid(crate::numbered_lines("219\n3a8")),
+            Err(PuzzleError::parse(2, 2, "a", "expected a digit"))
+        );
+        assert_eq!(
+            crate::digit_grid(crate::numbered_lines("219\n39")),
+            Err(PuzzleError::parse(
+                2,
+                1,
+                "39",
+                "expected 3 characters like the first line"
+            ))
+        );
+    }
+}
@@ -1,41 +1,173 @@
+mod coordinate;
+mod error;
+mod grid;
+mod section;
+
+pub use coordinate::{coordinate, coordinates};
+pub use error::InputError;
+pub use grid::{char_grid, digit_grid};
+pub use section::{sections, Section};
+
+use puzzle::{parse_token, PuzzleError};
+use std::fmt::Display;
+use std::io::{self, Read};
+use std::path::Path;
 use std::str::FromStr;
 
-pub fn read_lines(file_name: &str) -> Vec<String> {
-    std::fs::read_to_string(file_name)
-        .unwrap_or_else(|_| panic!("file not found: {}", file_name))
-        .lines()
-        .map(|line| line.to_string())
-        .collect()
+/// Unix line endings, no trailing whitespace on any line and no blank lines at the end, so days don't have to care
+/// about how the input was saved.
+pub fn normalize(input: &str) -> String {
+    let lines = input.lines().map(str::trim_end).collect::<Vec<_>>();
+    let length = lines
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(0, |last| last + 1);
+    lines[..length].join("\n")
 }
 
-pub fn read_lines_as<T: FromStr>(file_name: &str) -> Vec<T> {
-    std::fs::read_to_string(file_name)
-        .unwrap_or_else(|_| panic!("file not found: {}", file_name))
-        .lines()
-        .map(|line| match line.parse::<T>() {
-            Ok(n) => n,
-            Err(_) => panic!("Failed to parse"),
-        })
-        .collect()
+// Loading. Everything read through here is normalized.
+
+pub fn read_file(path: impl AsRef<Path>) -> io::Result<String> {
+    std::fs::read_to_string(path).map(|input| normalize(&input))
+}
+
+pub fn read_from(mut reader: impl Read) -> io::Result<String> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    Ok(normalize(&input))
+}
+
+pub fn read_lines(path: impl AsRef<Path>) -> io::Result<Vec<String>> {
+    read_file(path).map(|input| input.lines().map(|line| line.to_string()).collect())
+}
+
+/// Loads a file and parses it with any of the `&str` parsers, e.g. `parse_file(path, parse_lines::<u32>)`.
+pub fn parse_file<T>(
+    path: impl AsRef<Path>,
+    parse: impl FnOnce(&str) -> Result<T, PuzzleError>,
+) -> Result<T, InputError> {
+    Ok(parse(&read_file(path)?)?)
+}
+
+/// Same as [parse_file], for anything readable like stdin.
+pub fn parse_reader<T>(reader: impl Read, parse: impl FnOnce(&str) -> Result<T, PuzzleError>) -> Result<T, InputError> {
+    Ok(parse(&read_from(reader)?)?)
+}
+
+pub fn read_lines_as<T: FromStr>(path: impl AsRef<Path>) -> Result<Vec<T>, InputError>
+where
+    T::Err: Display,
+{
+    parse_file(path, parse_lines)
 }
 
-pub fn read_comma_separated_as<T: FromStr>(file_name: &str) -> Vec<T> {
-    std::fs::read_to_string(file_name)
-        .unwrap_or_else(|_| panic!("file not found: {}", file_name))
-        .split(',')
-        .map(|x| match x.parse::<T>() {
-            Ok(n) => n,
-            Err(_) => panic!("Failed to parse"),
-        })
+pub fn read_comma_separated_as<T: FromStr>(path: impl AsRef<Path>) -> Result<Vec<T>, InputError>
+where
+    T::Err: Display,
+{
+    parse_file(path, parse_comma_separated)
+}
+
+// Parsing. Line numbers are 1 based and match the ones an editor shows for the input.
+
+/// Lines paired with their line number.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines().enumerate().map(|(i, line)| (i + 1, line))
+}
+
+/// One value per line.
+pub fn parse_lines<T: FromStr>(input: &str) -> Result<Vec<T>, PuzzleError>
+where
+    T::Err: Display,
+{
+    numbered_lines(input)
+        .map(|(line_number, line)| parse_token(line_number, line, line))
         .collect()
 }
 
-// Reference based methods. The called holds the file string & everything is borrowed from there.
+/// Values separated by `separator` within a single line.
+pub fn parse_separated<T: FromStr>(line_number: usize, line: &str, separator: char) -> Result<Vec<T>, PuzzleError>
+where
+    T::Err: Display,
+{
+    line.split(separator)
+        .map(|token| parse_token(line_number, line, token.trim()))
+        .collect()
+}
 
-pub fn read_file(file_name: &str) -> String {
-    std::fs::read_to_string(file_name).expect("file not found!")
+/// A single line of comma separated values, like `3,4,3,1,2`.
+pub fn parse_comma_separated<T: FromStr>(input: &str) -> Result<Vec<T>, PuzzleError>
+where
+    T::Err: Display,
+{
+    parse_separated(1, input.trim_end(), ',')
 }
 
-pub fn lines(string: &String) -> impl Iterator<Item = &str> {
-    string.lines()
+#[cfg(test)]
+mod tests {
+    use crate::InputError;
+    use puzzle::PuzzleError;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(crate::normalize("1\r\n2  \r\n\r\n3\n\n\n"), "1\n2\n\n3");
+        assert_eq!(crate::normalize("  8  2\n 21  9\n"), "  8  2\n 21  9");
+        assert_eq!(crate::normalize("\n\n"), "");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(crate::parse_lines::<u32>("199\n200\n208"), Ok(vec![199, 200, 208]));
+        assert_eq!(
+            crate::parse_lines::<u32>("199\n2x0"),
+            Err(PuzzleError::parse(2, 1, "2x0", "invalid digit found in string"))
+        );
+        assert_eq!(
+            crate::parse_comma_separated::<u8>("3,4,3,1,2\n"),
+            Ok(vec![3, 4, 3, 1, 2])
+        );
+        assert_eq!(
+            crate::parse_separated::<u8>(4, "3, 4,x", ','),
+            Err(PuzzleError::parse(4, 6, "x", "invalid digit found in string"))
+        );
+    }
+
+    #[test]
+    fn test_read() {
+        let dir = std::env::temp_dir().join(format!("inpututils-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let lines = dir.join("lines.txt");
+        std::fs::write(&lines, "1\r\n2\r\nx3\r\n").unwrap();
+        assert_eq!(crate::read_lines(&lines).unwrap(), vec!["1", "2", "x3"]);
+        match crate::read_lines_as::<u32>(&lines) {
+            Err(InputError::Parse(error)) => {
+                assert_eq!(error, PuzzleError::parse(3, 1, "x3", "invalid digit found in string"))
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let comma_separated = dir.join("comma_separated.txt");
+        std::fs::write(&comma_separated, "3,4,3,1,2\n").unwrap();
+        assert_eq!(
+            crate::read_comma_separated_as::<u8>(&comma_separated).unwrap(),
+            vec![3, 4, 3, 1, 2]
+        );
+
+        assert!(matches!(
+            crate::read_lines_as::<u32>(dir.join("missing.txt")),
+            Err(InputError::Io(_))
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_reader() {
+        let reader = "199\r\n200\r\n".as_bytes();
+        assert_eq!(
+            crate::parse_reader(reader, crate::parse_lines::<u32>).unwrap(),
+            vec![199, 200]
+        );
+    }
 }
@@ -0,0 +1,77 @@
+/// A block of consecutive lines, separated from the next one by blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+    /// Line number of the first line of the section within the whole input.
+    pub first_line: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Section<'a> {
+    /// Lines paired with their line number within the whole input.
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(move |(i, &line)| (self.first_line + i, line))
+    }
+
+    /// Line number right after the section, where whatever is missing from it would go.
+    pub fn end_line(&self) -> usize {
+        self.first_line + self.lines.len()
+    }
+}
+
+/// Splits lines in blank line separated sections. Several blank lines in a row count as one separator.
+pub fn sections<'a>(lines: impl IntoIterator<Item = &'a str>) -> Vec<Section<'a>> {
+    let mut sections: Vec<Section> = Vec::new();
+    let mut current: Option<Section> = None;
+    for (i, line) in lines.into_iter().enumerate() {
+        if line.trim().is_empty() {
+            sections.extend(current.take());
+        } else {
+            current
+                .get_or_insert_with(|| Section {
+                    first_line: i + 1,
+                    lines: Vec::new(),
+                })
+                .lines
+                .push(line);
+        }
+    }
+    sections.extend(current);
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Section;
+
+    #[test]
+    fn test_sections() {
+        let input = "NNCB\n\nCH -> B\nHH -> N\n\n\nCB -> H\n";
+        assert_eq!(
+            crate::sections(input.lines()),
+            vec![
+                Section {
+                    first_line: 1,
+                    lines: vec!["NNCB"]
+                },
+                Section {
+                    first_line: 3,
+                    lines: vec!["CH -> B", "HH -> N"]
+                },
+                Section {
+                    first_line: 7,
+                    lines: vec!["CB -> H"]
+                },
+            ]
+        );
+        let sections = crate::sections(input.lines());
+        assert_eq!(
+            sections[1].numbered_lines().collect::<Vec<_>>(),
+            vec![(3, "CH -> B"), (4, "HH -> N")]
+        );
+        assert_eq!(sections[1].end_line(), 5);
+        assert!(crate::sections("".lines()).is_empty());
+    }
+}
@@ -0,0 +1,207 @@
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::convert::Infallible;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// What a part comes up with, whatever type the day computes it in. Answers are normalized so the same answer is
+/// always the same value: a number given as text is a number, and text over several lines is a grid. Only text written
+/// the way the number is, without leading zeros or a `+`, is a number, so `007` stays text and doesn't match `7`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i64),
+    /// Too big for an [Answer::Integer].
+    BigInteger(i128),
+    Text(String),
+    /// ASCII art, like the letters drawn by folding the transparent paper of day 13, one string per row.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    pub fn grid(rows: impl IntoIterator<Item = impl Into<String>>) -> Answer {
+        Answer::Grid(rows.into_iter().map(Into::into).collect())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(integer) => write!(f, "{}", integer),
+            Answer::BigInteger(integer) => write!(f, "{}", integer),
+            Answer::Text(text) => f.write_str(text),
+            Answer::Grid(rows) => f.write_str(&rows.join("\n")),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(answer: &str) -> Result<Answer, Infallible> {
+        Ok(if let Some(integer) = canonical_integer(answer) {
+            Answer::Integer(integer)
+        } else if let Some(integer) = canonical_integer(answer) {
+            Answer::BigInteger(integer)
+        } else if answer.contains('\n') {
+            Answer::grid(answer.lines())
+        } else {
+            Answer::Text(answer.to_string())
+        })
+    }
+}
+
+/// The number `answer` is written as, if it is written the way the number is.
+fn canonical_integer<T: FromStr + Display>(answer: &str) -> Option<T> {
+    answer.parse().ok().filter(|integer: &T| integer.to_string() == answer)
+}
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Answer {
+        match answer.parse() {
+            Ok(Answer::Text(_)) => Answer::Text(answer),
+            Ok(answer) => answer,
+            Err(infallible) => match infallible {},
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(answer: &str) -> Answer {
+        match answer.parse() {
+            Ok(answer) => answer,
+            Err(infallible) => match infallible {},
+        }
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(integer: i128) -> Answer {
+        i64::try_from(integer).map_or(Answer::BigInteger(integer), Answer::Integer)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(integer: u64) -> Answer {
+        Answer::from(i128::from(integer))
+    }
+}
+
+macro_rules! from_integer {
+    ($($integer:ty),*) => {$(
+        impl From<$integer> for Answer {
+            fn from(integer: $integer) -> Answer {
+                Answer::Integer(i64::from(integer))
+            }
+        }
+    )*};
+}
+
+from_integer!(u8, u16, u32, i8, i16, i32, i64);
+
+macro_rules! from_wide_integer {
+    ($($integer:ty),*) => {$(
+        impl From<$integer> for Answer {
+            fn from(integer: $integer) -> Answer {
+                i128::try_from(integer).map_or_else(|_| Answer::Text(integer.to_string()), Answer::from)
+            }
+        }
+    )*};
+}
+
+from_wide_integer!(usize, isize, u128);
+
+/// Integers that fit in JSON numbers stay numbers, everything else is written as text.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Integer(integer) => serializer.serialize_i64(*integer),
+            answer => serializer.collect_str(answer),
+        }
+    }
+}
+
+/// Reads a number or a string, normalized like any other answer.
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Answer, D::Error> {
+        struct AnswerVisitor;
+
+        impl Visitor<'_> for AnswerVisitor {
+            type Value = Answer;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an integer or a string")
+            }
+
+            fn visit_i64<E: de::Error>(self, integer: i64) -> Result<Answer, E> {
+                Ok(Answer::from(integer))
+            }
+
+            fn visit_u64<E: de::Error>(self, integer: u64) -> Result<Answer, E> {
+                Ok(Answer::from(integer))
+            }
+
+            fn visit_i128<E: de::Error>(self, integer: i128) -> Result<Answer, E> {
+                Ok(Answer::from(integer))
+            }
+
+            fn visit_u128<E: de::Error>(self, integer: u128) -> Result<Answer, E> {
+                Ok(Answer::from(integer))
+            }
+
+            fn visit_str<E: de::Error>(self, answer: &str) -> Result<Answer, E> {
+                Ok(Answer::from(answer))
+            }
+        }
+
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Answer;
+
+    #[test]
+    fn test_normalization() {
+        assert_eq!(Answer::from(1482usize), Answer::Integer(1482));
+        assert_eq!(Answer::from(-3i32), Answer::Integer(-3));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInteger(u64::MAX as i128));
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+        assert_eq!(Answer::from("1482".to_string()), Answer::Integer(1482));
+        assert_eq!(
+            Answer::from("18446744073709551615"),
+            Answer::BigInteger(u64::MAX as i128)
+        );
+        assert_eq!(Answer::from("NNCB"), Answer::Text("NNCB".to_string()));
+        assert_eq!(Answer::from("007"), Answer::Text("007".to_string()));
+        assert_eq!(Answer::from("+7".to_string()), Answer::Text("+7".to_string()));
+        assert_eq!(Answer::from("-0"), Answer::Text("-0".to_string()));
+        assert_ne!(Answer::from("007"), Answer::from(7u8));
+        assert_eq!(Answer::from("#.#\n.#."), Answer::grid(["#.#", ".#."]));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::Integer(-3).to_string(), "-3");
+        assert_eq!(Answer::BigInteger(1 << 70).to_string(), "1180591620717411303424");
+        assert_eq!(Answer::grid(["#.#", ".#."]).to_string(), "#.#\n.#.");
+    }
+
+    #[test]
+    fn test_serde() {
+        let answers = vec![
+            Answer::Integer(1482),
+            Answer::BigInteger(1 << 70),
+            Answer::Text("NNCB".to_string()),
+            Answer::grid(["#.", ".#"]),
+        ];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(json, r##"[1482,"1180591620717411303424","NNCB","#.\n.#"]"##);
+        assert_eq!(serde_json::from_str::<Vec<Answer>>(&json).unwrap(), answers);
+        assert_eq!(
+            serde_json::from_str::<Answer>(r#""1482""#).unwrap(),
+            Answer::Integer(1482)
+        );
+        assert!(serde_json::from_str::<Answer>("1.5").is_err());
+    }
+}
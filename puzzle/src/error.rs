@@ -0,0 +1,174 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Why a day could not produce an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    /// The input doesn't follow the puzzle format. Lines and columns are 1 based.
+    Parse {
+        day: Option<u8>,
+        line: usize,
+        column: usize,
+        text: String,
+        reason: String,
+    },
+    /// The input is well formed, but the puzzle has no answer for it.
+    NoSolution { day: Option<u8>, reason: String },
+    /// The solution panicked instead of returning an error.
+    Panicked { day: Option<u8>, message: String },
+    /// The solution didn't finish within the time it was given.
+    TimedOut { day: Option<u8>, limit: Duration },
+}
+
+impl PuzzleError {
+    pub fn parse(line: usize, column: usize, text: impl Into<String>, reason: impl Into<String>) -> PuzzleError {
+        PuzzleError::Parse {
+            day: None,
+            line,
+            column,
+            text: text.into(),
+            reason: reason.into(),
+        }
+    }
+
+    pub fn no_solution(reason: impl Into<String>) -> PuzzleError {
+        PuzzleError::NoSolution {
+            day: None,
+            reason: reason.into(),
+        }
+    }
+
+    pub fn panicked(message: impl Into<String>) -> PuzzleError {
+        PuzzleError::Panicked {
+            day: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn timed_out(limit: Duration) -> PuzzleError {
+        PuzzleError::TimedOut { day: None, limit }
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        match self {
+            PuzzleError::Parse { day, .. }
+            | PuzzleError::NoSolution { day, .. }
+            | PuzzleError::Panicked { day, .. }
+            | PuzzleError::TimedOut { day, .. } => *day,
+        }
+    }
+
+    /// Tags the error with the day it comes from, unless it already is.
+    pub fn in_day(mut self, new_day: u8) -> PuzzleError {
+        match &mut self {
+            PuzzleError::Parse { day, .. }
+            | PuzzleError::NoSolution { day, .. }
+            | PuzzleError::Panicked { day, .. }
+            | PuzzleError::TimedOut { day, .. } => {
+                day.get_or_insert(new_day);
+            }
+        }
+        self
+    }
+
+    /// Moves a parse error to another line, for parsers that only ever see one line at a time.
+    pub fn at_line(mut self, new_line: usize) -> PuzzleError {
+        if let PuzzleError::Parse { line, .. } = &mut self {
+            *line = new_line;
+        }
+        self
+    }
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(day) = self.day() {
+            write!(f, "day {}: ", day)?;
+        }
+        match self {
+            PuzzleError::Parse {
+                line,
+                column,
+                text,
+                reason,
+                ..
+            } => write!(f, "line {}, column {}: {}: `{}`", line, column, reason, text),
+            PuzzleError::NoSolution { reason, .. } => write!(f, "no solution: {}", reason),
+            PuzzleError::Panicked { message, .. } => write!(f, "panicked: {}", message),
+            PuzzleError::TimedOut { limit, .. } => write!(f, "timed out after {:?}", limit),
+        }
+    }
+}
+
+impl std::error::Error for PuzzleError {}
+
+/// Column (1 based, in chars) where `token` starts within `line`. `token` must be a slice of `line`.
+pub fn column_of(line: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize)
+        .checked_sub(line.as_ptr() as usize)
+        .filter(|&offset| offset + token.len() <= line.len())
+        .unwrap_or(0);
+    line[..offset].chars().count() + 1
+}
+
+/// Parses `token`, a slice of `line`, reporting where it is when it isn't a valid `T`.
+pub fn parse_token<T: FromStr>(line_number: usize, line: &str, token: &str) -> Result<T, PuzzleError>
+where
+    T::Err: Display,
+{
+    token
+        .parse::<T>()
+        .map_err(|error| PuzzleError::parse(line_number, column_of(line, token), token, error.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PuzzleError;
+
+    #[test]
+    fn test_column_of() {
+        let line = "0,9 -> 5,9";
+        assert_eq!(crate::column_of(line, &line[0..1]), 1);
+        assert_eq!(crate::column_of(line, &line[7..10]), 8);
+        assert_eq!(crate::column_of(line, "unrelated"), 1);
+    }
+
+    #[test]
+    fn test_parse_token() {
+        let line = "12,x4,5";
+        assert_eq!(crate::parse_token::<u8>(3, line, &line[0..2]), Ok(12));
+        assert_eq!(
+            crate::parse_token::<u8>(3, line, &line[3..5]),
+            Err(PuzzleError::parse(3, 4, "x4", "invalid digit found in string"))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let error = PuzzleError::parse(3, 4, "x4", "invalid digit found in string");
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 4: invalid digit found in string: `x4`"
+        );
+        assert_eq!(
+            error.in_day(5).to_string(),
+            "day 5: line 3, column 4: invalid digit found in string: `x4`"
+        );
+        assert_eq!(
+            PuzzleError::no_solution("no board ever wins")
+                .in_day(4)
+                .in_day(6)
+                .to_string(),
+            "day 4: no solution: no board ever wins"
+        );
+        assert_eq!(
+            PuzzleError::panicked("index out of bounds").in_day(18).to_string(),
+            "day 18: panicked: index out of bounds"
+        );
+        assert_eq!(
+            PuzzleError::timed_out(std::time::Duration::from_secs(10)).to_string(),
+            "timed out after 10s"
+        );
+    }
+}
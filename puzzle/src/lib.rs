@@ -0,0 +1,195 @@
+mod answer;
+mod error;
+
+pub use answer::Answer;
+pub use error::{column_of, parse_token, PuzzleError};
+use std::fmt::{self, Display};
+
+/// A day of the calendar: how to turn the raw puzzle input into something both parts can work with, and the two parts.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input: Sync + 'static;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError>;
+
+    /// Other ways of solving the parts, kept around to compare them against `part1` and `part2`.
+    fn variants() -> Vec<Variant<Self::Input>> {
+        Vec::new()
+    }
+}
+
+/// An alternative solution of a part, like `part1_functional` next to `part1`.
+pub struct Variant<I> {
+    /// The name of the function, e.g. `part1_with_regex`.
+    pub name: &'static str,
+    pub part: Part,
+    pub solve: fn(&I) -> Result<Answer, PuzzleError>,
+}
+
+/// Object safe view of a [Solution], so days with different input and answer types can live in the same list.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, PuzzleError>;
+}
+
+/// A parsed input, ready to be solved. Both parts can be solved at the same time from different threads.
+pub trait Parsed: Sync {
+    fn part1(&self) -> Result<Answer, PuzzleError>;
+    fn part2(&self) -> Result<Answer, PuzzleError>;
+
+    fn solve(&self, part: Part) -> Result<Answer, PuzzleError> {
+        match part {
+            Part::One => self.part1(),
+            Part::Two => self.part2(),
+        }
+    }
+
+    /// Names and parts of the [Variant]s of the day.
+    fn variants(&self) -> Vec<(&'static str, Part)>;
+
+    /// Solves the input with the variant of that name, if there is one.
+    fn solve_variant(&self, name: &str) -> Option<Result<Answer, PuzzleError>>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+struct ParsedInput<S: Solution>(S::Input);
+
+// Errors leaving a day through the object safe interface are tagged with the day they come from.
+impl<S: Solution> Parsed for ParsedInput<S> {
+    fn part1(&self) -> Result<Answer, PuzzleError> {
+        S::part1(&self.0).map(Into::into).map_err(|error| error.in_day(S::DAY))
+    }
+
+    fn part2(&self) -> Result<Answer, PuzzleError> {
+        S::part2(&self.0).map(Into::into).map_err(|error| error.in_day(S::DAY))
+    }
+
+    fn variants(&self) -> Vec<(&'static str, Part)> {
+        S::variants()
+            .iter()
+            .map(|variant| (variant.name, variant.part))
+            .collect()
+    }
+
+    fn solve_variant(&self, name: &str) -> Option<Result<Answer, PuzzleError>> {
+        let variant = S::variants().into_iter().find(|variant| variant.name == name)?;
+        Some((variant.solve)(&self.0).map_err(|error| error.in_day(S::DAY)))
+    }
+}
+
+impl<S: Solution + Sync + 'static> Puzzle for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, PuzzleError> {
+        match S::parse(input) {
+            Ok(input) => Ok(Box::new(ParsedInput::<S>(input))),
+            Err(error) => Err(error.in_day(S::DAY)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_token, Answer, Part, Puzzle, PuzzleError, Solution, Variant};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+
+        type Input = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+            input
+                .lines()
+                .enumerate()
+                .map(|(i, line)| parse_token(i + 1, line, line))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+            if input.len() < 2 {
+                return Err(PuzzleError::no_solution("needs at least two numbers"));
+            }
+            Ok(format!("{:?}", input))
+        }
+
+        fn variants() -> Vec<Variant<Self::Input>> {
+            vec![Variant {
+                name: "part1_reversed",
+                part: Part::One,
+                solve: |input| Ok(input.iter().rev().sum::<u32>().into()),
+            }]
+        }
+    }
+
+    #[test]
+    fn test_puzzle() {
+        let puzzle: &dyn Puzzle = &Sum;
+        assert_eq!(puzzle.day(), 1);
+        let parsed = puzzle.parse("1\n2\n3").unwrap();
+        assert_eq!(parsed.part1(), Ok(Answer::Integer(6)));
+        assert_eq!(parsed.part2(), Ok(Answer::Text("[1, 2, 3]".to_string())));
+        assert_eq!(parsed.solve(Part::Two), parsed.part2());
+    }
+
+    #[test]
+    fn test_variants() {
+        let parsed = Sum.parse("1\n2\n3").unwrap();
+        assert_eq!(parsed.variants(), vec![("part1_reversed", Part::One)]);
+        assert_eq!(parsed.solve_variant("part1_reversed"), Some(Ok(Answer::Integer(6))));
+        assert_eq!(parsed.solve_variant("part2_reversed"), None);
+    }
+
+    #[test]
+    fn test_errors_are_tagged_with_the_day() {
+        let puzzle: &dyn Puzzle = &Sum;
+        let error = puzzle.parse("1\n2\nthree").err().unwrap();
+        assert_eq!(error.day(), Some(1));
+        assert_eq!(
+            error.to_string(),
+            "day 1: line 3, column 1: invalid digit found in string: `three`"
+        );
+
+        let parsed = puzzle.parse("1").unwrap();
+        assert_eq!(
+            parsed.part2(),
+            Err(PuzzleError::no_solution("needs at least two numbers").in_day(1))
+        );
+    }
+}
@@ -0,0 +1,373 @@
+use crate::runner::DayReport;
+use puzzle::{Answer, Part, PuzzleError};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+
+/// Known answers for an input, read from a manifest next to it: `inputs/day01/input.toml` (or `.json`) holds the
+/// answers of `inputs/day01/input.txt`. Either part can be left out when it isn't known yet. Answers the site rejected
+/// are kept too, so `submit` doesn't send them again:
+///
+/// ```toml
+/// part1 = 1482
+/// part2 = 1518
+///
+/// [[wrong]]
+/// part = 1
+/// answer = 1176
+/// hint = "too low"
+/// ```
+///
+/// Numbers can be quoted or not, and multi-line answers are grids, see [Answer].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<WrongAnswer>,
+}
+
+/// An answer the site rejected for a part.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WrongAnswer {
+    pub part: u8,
+    pub answer: Answer,
+    /// Which side of the right answer it was on, when the site said.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Hint>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Hint {
+    #[serde(rename = "too high")]
+    TooHigh,
+    #[serde(rename = "too low")]
+    TooLow,
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: Answer) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+
+    /// Why `answer` can't be the right one for `part`, going by what is already known: the right answer, the answers
+    /// rejected before, and the bounds the hints of those give.
+    pub fn rejects(&self, part: Part, answer: &Answer) -> Option<String> {
+        match self.get(part) {
+            Some(right) if right == answer => return None,
+            Some(right) => return Some(format!("the right answer is already known: {}", right)),
+            None => {}
+        }
+        let wrong = self.wrong.iter().filter(|wrong| wrong.part == part.number());
+        for wrong in wrong {
+            if &wrong.answer == answer {
+                return Some(format!("{} was already rejected", answer));
+            }
+            let beyond = match (wrong.hint, number(answer), number(&wrong.answer)) {
+                (Some(Hint::TooHigh), Some(answer), Some(bound)) => answer > bound,
+                (Some(Hint::TooLow), Some(answer), Some(bound)) => answer < bound,
+                _ => false,
+            };
+            if beyond {
+                return Some(format!("{} was already {}", wrong.answer, wrong.hint.unwrap()));
+            }
+        }
+        None
+    }
+
+    /// Reads the manifest of an input, if it has one.
+    pub fn load(input: &Path) -> Result<Option<Answers>, String> {
+        for manifest in manifests(input) {
+            let answers = match std::fs::read_to_string(&manifest) {
+                Ok(answers) => answers,
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(error) => return Err(format!("cannot read {}: {}", manifest.display(), error)),
+            };
+            let parsed = if is_json(&manifest) {
+                serde_json::from_str(&answers).map_err(|error| error.to_string())
+            } else {
+                toml::from_str(&answers).map_err(|error| error.to_string())
+            };
+            return parsed
+                .map(Some)
+                .map_err(|error| format!("invalid answers in {}: {}", manifest.display(), error));
+        }
+        Ok(None)
+    }
+
+    /// Writes the manifest of an input, in the format it already has, TOML for a new one.
+    pub fn save(&self, input: &Path) -> Result<(), String> {
+        let [toml_manifest, json_manifest] = manifests(input);
+        let manifest = if !toml_manifest.is_file() && json_manifest.is_file() {
+            json_manifest
+        } else {
+            toml_manifest
+        };
+        let answers = if is_json(&manifest) {
+            serde_json::to_string_pretty(self)
+                .map(|json| json + "\n")
+                .map_err(|error| error.to_string())
+        } else {
+            toml::to_string(self).map_err(|error| error.to_string())
+        };
+        let answers = answers.map_err(|error| format!("cannot write answers for {}: {}", input.display(), error))?;
+        std::fs::write(&manifest, answers).map_err(|error| format!("cannot write {}: {}", manifest.display(), error))
+    }
+}
+
+fn is_json(manifest: &Path) -> bool {
+    manifest.extension().is_some_and(|extension| extension == "json")
+}
+
+/// The value of a numeric answer, to compare it with the bounds the hints give.
+fn number(answer: &Answer) -> Option<i128> {
+    match answer {
+        Answer::Integer(integer) => Some(i128::from(*integer)),
+        Answer::BigInteger(integer) => Some(*integer),
+        _ => None,
+    }
+}
+
+/// Where the answers of an input can be recorded, in order of preference.
+pub fn manifests(input: &Path) -> [PathBuf; 2] {
+    [input.with_extension("toml"), input.with_extension("json")]
+}
+
+/// Outcome of checking a part against its recorded answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: Answer,
+        actual: Result<Answer, PuzzleError>,
+    },
+    /// There is no recorded answer, only what the solution came up with.
+    Missing {
+        actual: Result<Answer, PuzzleError>,
+    },
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Grids go on their own lines.
+        fn answer(answer: &Answer) -> String {
+            match answer {
+                Answer::Grid(_) => format!("\n{}\n", answer),
+                answer => answer.to_string(),
+            }
+        }
+
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail {
+                expected,
+                actual: Ok(actual),
+            } => write!(f, "FAIL, expected {} but got {}", answer(expected), answer(actual)),
+            Verdict::Fail {
+                expected,
+                actual: Err(error),
+            } => write!(f, "FAIL, expected {} but got error: {}", answer(expected), error),
+            Verdict::Missing { actual: Ok(actual) } => write!(f, "missing, got {}", answer(actual)),
+            Verdict::Missing { actual: Err(error) } => write!(f, "missing, got error: {}", error),
+        }
+    }
+}
+
+/// Checks every part of a run against the recorded answers.
+pub fn verify(report: &DayReport, parts: &[Part], answers: &Answers) -> Vec<(Part, Verdict)> {
+    parts
+        .iter()
+        .map(|&part| {
+            let actual = match &report.parts {
+                Ok(part_reports) => part_reports
+                    .iter()
+                    .find(|part_report| part_report.part == part)
+                    .map_or_else(
+                        || Err(PuzzleError::no_solution("the part didn't run")),
+                        |part_report| part_report.answer.clone(),
+                    ),
+                Err(error) => Err(error.clone()),
+            };
+            let verdict = match answers.get(part) {
+                None => Verdict::Missing { actual },
+                Some(expected) if actual.as_ref() == Ok(expected) => Verdict::Pass,
+                Some(expected) => Verdict::Fail {
+                    expected: expected.clone(),
+                    actual,
+                },
+            };
+            (part, verdict)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{Answers, Hint, Verdict, WrongAnswer};
+    use crate::runner::run_day;
+    use puzzle::{Answer, Part, PuzzleError};
+    use std::path::Path;
+
+    #[test]
+    fn test_parse_manifests() {
+        let answers: Answers = toml::from_str("part1 = 1482\npart2 = '''\n#.#\n.#.'''\n").unwrap();
+        assert_eq!(answers.get(Part::One), Some(&Answer::Integer(1482)));
+        assert_eq!(answers.get(Part::Two), Some(&Answer::from("#.#\n.#.")));
+
+        let answers: Answers = serde_json::from_str(r#"{"part2": "1518"}"#).unwrap();
+        assert_eq!(
+            answers,
+            Answers {
+                part1: None,
+                part2: Some(Answer::Integer(1518)),
+                wrong: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_load() {
+        let answers = Answers::load(&crate::input_path(1, "input")).unwrap().unwrap();
+        assert_eq!(answers.get(Part::One), Some(&Answer::Integer(1482)));
+        let answers = Answers::load(&crate::input_path(16, "example-2")).unwrap().unwrap();
+        assert_eq!(answers.get(Part::One), None);
+        assert_eq!(answers.get(Part::Two), Some(&Answer::Integer(3)));
+        assert_eq!(Answers::load(Path::new("inputs/day99/input.txt")), Ok(None));
+    }
+
+    #[test]
+    fn test_verify() {
+        let puzzle = crate::find_day(1).unwrap();
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+        let report = run_day(puzzle, input, &Part::ALL, 1);
+        let answers = Answers {
+            part1: Some(Answer::Integer(7)),
+            part2: None,
+            wrong: Vec::new(),
+        };
+        assert_eq!(
+            crate::answers::verify(&report, &Part::ALL, &answers),
+            vec![
+                (Part::One, Verdict::Pass),
+                (
+                    Part::Two,
+                    Verdict::Missing {
+                        actual: Ok(Answer::Integer(5))
+                    }
+                )
+            ]
+        );
+
+        let answers = Answers {
+            part1: Some(Answer::Integer(8)),
+            part2: Some(Answer::Integer(5)),
+            wrong: Vec::new(),
+        };
+        let report = run_day(puzzle, "199\nx", &Part::ALL, 1);
+        assert_eq!(
+            crate::answers::verify(&report, &[Part::One], &answers),
+            vec![(
+                Part::One,
+                Verdict::Fail {
+                    expected: Answer::Integer(8),
+                    actual: Err(PuzzleError::parse(2, 1, "x", "invalid digit found in string").in_day(1))
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn test_rejects() {
+        let answers: Answers = toml::from_str(
+            "part2 = 4748\n\
+             [[wrong]]\npart = 1\nanswer = 1176\n\
+             [[wrong]]\npart = 1\nanswer = 9000\nhint = 'too high'\n\
+             [[wrong]]\npart = 1\nanswer = 5000\nhint = 'too low'\n",
+        )
+        .unwrap();
+        assert_eq!(
+            answers.wrong[1],
+            WrongAnswer {
+                part: 1,
+                answer: Answer::Integer(9000),
+                hint: Some(Hint::TooHigh)
+            }
+        );
+        assert_eq!(answers.rejects(Part::One, &Answer::Integer(8911)), None);
+        assert_eq!(
+            answers.rejects(Part::One, &Answer::Integer(1176)),
+            Some("1176 was already rejected".to_string())
+        );
+        assert_eq!(
+            answers.rejects(Part::One, &Answer::Integer(9001)),
+            Some("9000 was already too high".to_string())
+        );
+        assert_eq!(
+            answers.rejects(Part::One, &Answer::Integer(4950)),
+            Some("5000 was already too low".to_string())
+        );
+        assert_eq!(
+            answers.rejects(Part::Two, &Answer::Integer(1176)),
+            Some("the right answer is already known: 4748".to_string())
+        );
+        assert_eq!(answers.rejects(Part::Two, &Answer::Integer(4748)), None);
+    }
+
+    #[test]
+    fn test_save() {
+        let dir = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("input.txt");
+        let mut answers = Answers::default();
+        answers.set(Part::Two, Answer::from("#.\n.#"));
+        answers.wrong.push(WrongAnswer {
+            part: 1,
+            answer: Answer::Integer(1176),
+            hint: Some(Hint::TooLow),
+        });
+        answers.save(&input).unwrap();
+        assert_eq!(Answers::load(&input), Ok(Some(answers.clone())));
+
+        // An existing JSON manifest stays JSON
+        std::fs::remove_file(dir.join("input.toml")).unwrap();
+        std::fs::write(dir.join("input.json"), "{}").unwrap();
+        answers.save(&input).unwrap();
+        assert!(!dir.join("input.toml").exists());
+        assert_eq!(Answers::load(&input), Ok(Some(answers)));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_display_verdict() {
+        let verdict = Verdict::Fail {
+            expected: Answer::Integer(8),
+            actual: Ok(Answer::Integer(7)),
+        };
+        assert_eq!(verdict.to_string(), "FAIL, expected 8 but got 7");
+        let verdict = Verdict::Missing {
+            actual: Ok(Answer::from("#.\n.#")),
+        };
+        assert_eq!(verdict.to_string(), "missing, got \n#.\n.#\n");
+    }
+}
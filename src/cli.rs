@@ -0,0 +1,186 @@
+use advent_of_code_2021::output::{parse_format, Format};
+use advent_of_code_2021::runner::{parse_day_range, parse_part, parse_timeout};
+use clap::{Args, Parser, Subcommand};
+use puzzle::Part;
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+/// Advent of Code 2021 solutions. Runs every day when no command is given.
+#[derive(Parser)]
+#[command(version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Run the solutions, all days by default.
+    Run(RunArgs),
+    /// Check the solutions against every input with recorded answers (`inputs/dayNN/<name>.toml` next to the `.txt`).
+    Verify(VerifyArgs),
+    /// Print a random but valid input for a day, e.g. to benchmark with `BENCH_INPUT` or to test larger inputs.
+    Generate(GenerateArgs),
+    /// Check that the variants of the days (`part1_naive`, ...) give the same answers as the parts on generated inputs.
+    Crosscheck(CrosscheckArgs),
+    /// Download the puzzle inputs to `inputs/dayNN/input.txt`, with the session token from `AOC_SESSION` or `aoc.toml`.
+    Fetch(FetchArgs),
+    /// Submit the answer of a part and record the verdict next to the input, refusing answers known to be wrong.
+    Submit(SubmitArgs),
+    /// Create the `dayNN` crate of a new day and register it in `Cargo.toml`, `DAYS` and the main tests.
+    NewDay(NewDayArgs),
+    /// List the examples fenced in the puzzle texts of the days, or write them to `inputs/dayNN/example*.txt`.
+    Examples(ExamplesArgs),
+}
+
+#[derive(Args, Default)]
+pub struct RunArgs {
+    /// Run a single day.
+    #[arg(short, long, conflicts_with = "days")]
+    pub day: Option<u8>,
+
+    /// Run a range of days, e.g. `3..7` or `3-7` (inclusive).
+    #[arg(long, value_parser = parse_day_range)]
+    pub days: Option<RangeInclusive<u8>>,
+
+    /// Run only one part.
+    #[arg(short, long, value_parser = parse_part)]
+    pub part: Option<Part>,
+
+    /// Read the input from this file instead of `inputs/dayNN/input.txt`; `-` reads from stdin.
+    #[arg(short, long, requires = "day")]
+    pub input: Option<String>,
+
+    /// Run the input with this name in `inputs/dayNN`, e.g. `example` for `inputs/dayNN/example.txt`.
+    #[arg(short, long, conflicts_with = "input")]
+    pub name: Option<String>,
+
+    /// Print how long parsing and each part took, per day and in total.
+    #[arg(short, long)]
+    pub time: bool,
+
+    /// Run every step this many times and report the min and median times. Implies `--time`.
+    #[arg(long, value_parser = clap::value_parser!(u16).range(1..))]
+    pub repeat: Option<u16>,
+
+    /// Run the days on a pool of threads. Answers are still printed in calendar order.
+    #[arg(long)]
+    pub parallel: bool,
+
+    /// Threads for `--parallel`, one per core by default. Implies `--parallel`.
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..))]
+    pub jobs: Option<u16>,
+
+    /// Also solve both parts of a day at the same time. Implies `--parallel`.
+    #[arg(long)]
+    pub parallel_parts: bool,
+
+    /// Give up on a day taking longer than this, e.g. `500ms`, `10s` or `2m`, and carry on with the others.
+    #[arg(long, value_parser = parse_timeout)]
+    pub timeout: Option<Duration>,
+
+    /// Print the answers as `text`, or as `json` or `csv` records with timings and errors.
+    #[arg(short, long, value_parser = parse_format, default_value = "text")]
+    pub format: Format,
+}
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    /// Verify a single day.
+    #[arg(short, long, conflicts_with = "days")]
+    pub day: Option<u8>,
+
+    /// Verify a range of days, e.g. `3..7` or `3-7` (inclusive).
+    #[arg(long, value_parser = parse_day_range)]
+    pub days: Option<RangeInclusive<u8>>,
+}
+
+#[derive(Args)]
+pub struct GenerateArgs {
+    /// The day to generate an input for.
+    #[arg(short, long)]
+    pub day: u8,
+
+    /// How large the input is, in the unit of the day (lines, side of a grid...). The size of the real inputs by
+    /// default.
+    #[arg(short, long)]
+    pub size: Option<usize>,
+
+    /// The same seed always gives the same input.
+    #[arg(long, default_value_t = 0)]
+    pub seed: u64,
+}
+
+#[derive(Args)]
+pub struct CrosscheckArgs {
+    /// Cross-check a single day.
+    #[arg(short, long, conflicts_with = "days")]
+    pub day: Option<u8>,
+
+    /// Cross-check a range of days, e.g. `3..7` or `3-7` (inclusive).
+    #[arg(long, value_parser = parse_day_range)]
+    pub days: Option<RangeInclusive<u8>>,
+
+    /// Inputs to generate for every size.
+    #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u64).range(1..))]
+    pub seeds: u64,
+}
+
+#[derive(Args)]
+pub struct FetchArgs {
+    /// Download a single day.
+    #[arg(short, long, conflicts_with = "days")]
+    pub day: Option<u8>,
+
+    /// Download a range of days, e.g. `3..7` or `3-7` (inclusive).
+    #[arg(long, value_parser = parse_day_range)]
+    pub days: Option<RangeInclusive<u8>>,
+
+    /// Download the inputs again even when they are already there.
+    #[arg(long)]
+    pub force: bool,
+
+    /// Download from this server instead, e.g. a local stand-in. Overrides `AOC_BASE_URL` and `aoc.toml`.
+    #[arg(long)]
+    pub base_url: Option<String>,
+}
+
+#[derive(Args)]
+pub struct SubmitArgs {
+    /// The day to submit an answer for.
+    #[arg(short, long)]
+    pub day: u8,
+
+    /// The part to submit an answer for.
+    #[arg(short, long, value_parser = parse_part)]
+    pub part: Part,
+
+    /// Submit this answer instead of solving `inputs/dayNN/input.txt`.
+    #[arg(short, long)]
+    pub answer: Option<String>,
+
+    /// Submit to this server instead, e.g. a local stand-in. Overrides `AOC_BASE_URL` and `aoc.toml`.
+    #[arg(long)]
+    pub base_url: Option<String>,
+}
+
+#[derive(Args)]
+pub struct NewDayArgs {
+    /// The day to create, from 1 to 25.
+    pub day: u8,
+}
+
+#[derive(Args)]
+pub struct ExamplesArgs {
+    /// The examples of a single day.
+    #[arg(short, long, conflicts_with = "days")]
+    pub day: Option<u8>,
+
+    /// The examples of a range of days, e.g. `3..7` or `3-7` (inclusive).
+    #[arg(long, value_parser = parse_day_range)]
+    pub days: Option<RangeInclusive<u8>>,
+
+    /// Write the examples and their answers to `inputs/dayNN`, replacing the example inputs of the same name.
+    #[arg(short, long)]
+    pub write: bool,
+}
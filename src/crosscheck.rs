@@ -0,0 +1,123 @@
+use crate::runner::catch_panic;
+use inputgen::Generator;
+use puzzle::{Answer, Part, Puzzle, PuzzleError};
+use std::fmt::{self, Display};
+use std::ops::Range;
+
+/// Sizes of the generated inputs, smallest first so the first counterexample found is already small.
+pub const SIZES: [usize; 9] = [1, 2, 3, 5, 8, 13, 20, 50, 100];
+
+/// A generated input on which a variant doesn't give the same answer as the part it stands in for, shrunk as far as
+/// it goes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub variant: &'static str,
+    pub part: Part,
+    pub seed: u64,
+    pub input: String,
+    pub expected: Result<Answer, PuzzleError>,
+    pub actual: Result<Answer, PuzzleError>,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let answer = |answer: &Result<Answer, PuzzleError>| match answer {
+            Ok(answer) => answer.to_string(),
+            Err(error) => error.to_string(),
+        };
+        writeln!(
+            f,
+            "Day {}: {} disagrees with part {} (seed {}): {} instead of {} on",
+            self.day,
+            self.variant,
+            self.part,
+            self.seed,
+            answer(&self.actual),
+            answer(&self.expected)
+        )?;
+        write!(f, "{}", self.input)
+    }
+}
+
+/// Solves inputs of every size in `sizes` and every seed with each variant of the day and the part it's an
+/// alternative of, and returns the first input each variant disagrees on, shrunk. Panics count as answers, so a variant
+/// panicking where the part doesn't is a mismatch too.
+pub fn cross_check(puzzle: &dyn Puzzle, generator: &Generator, sizes: &[usize], seeds: Range<u64>) -> Vec<Mismatch> {
+    let day = puzzle.day();
+    let Ok(parsed) = puzzle.parse(&generator.generate(1, 0)) else {
+        return Vec::new();
+    };
+    let mut mismatches = Vec::new();
+    for (variant, part) in parsed.variants() {
+        let disagreement = |input: &str| -> Option<(Result<Answer, PuzzleError>, Result<Answer, PuzzleError>)> {
+            // Inputs that don't parse aren't counterexamples, they only come up while shrinking.
+            let parsed = puzzle.parse(input).ok()?;
+            let expected = catch_panic(day, || parsed.solve(part));
+            let actual = catch_panic(day, || parsed.solve_variant(variant).unwrap());
+            let agree = match (&expected, &actual) {
+                (Ok(expected), Ok(actual)) => expected == actual,
+                (Err(_), Err(_)) => true,
+                _ => false,
+            };
+            (!agree).then_some((expected, actual))
+        };
+        let counterexample = sizes
+            .iter()
+            .flat_map(|&size| seeds.clone().map(move |seed| (size, seed)))
+            .find_map(|(size, seed)| {
+                let input = generator.generate(size, seed);
+                disagreement(&input).map(|_| (seed, input))
+            });
+        if let Some((seed, input)) = counterexample {
+            let input = shrink(input, |input| disagreement(input).is_some());
+            let (expected, actual) = disagreement(&input).unwrap();
+            mismatches.push(Mismatch {
+                day,
+                variant,
+                part,
+                seed,
+                input,
+                expected,
+                actual,
+            });
+        }
+    }
+    mismatches
+}
+
+/// Removes lines, or comma separated values from a single line input, for as long as the input still `fails`.
+/// Chunks are removed largest first, down to single items.
+pub fn shrink(input: String, fails: impl Fn(&str) -> bool) -> String {
+    let separator = if input.contains('\n') { "\n" } else { "," };
+    let mut items = input.split(separator).map(str::to_string).collect::<Vec<_>>();
+    let mut chunk = items.len() / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        while start < items.len() {
+            let mut candidate = items.clone();
+            candidate.drain(start..(start + chunk).min(items.len()));
+            if !candidate.is_empty() && fails(&candidate.join(separator)) {
+                items = candidate;
+            } else {
+                start += chunk;
+            }
+        }
+        chunk /= 2;
+    }
+    items.join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crosscheck::shrink;
+
+    #[test]
+    fn test_shrink() {
+        let input = (1..=20).map(|n| n.to_string()).collect::<Vec<_>>().join("\n");
+        let fails = |input: &str| input.lines().any(|line| line == "7") && input.lines().any(|line| line == "13");
+        assert_eq!(shrink(input, fails), "7\n13");
+        assert_eq!(shrink("1,2,3,4".to_string(), |input| input.contains('3')), "3");
+        assert_eq!(shrink("1,2".to_string(), |_| false), "1,2");
+    }
+}
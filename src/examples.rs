@@ -0,0 +1,165 @@
+use crate::answers::Answers;
+use puzzle::{Answer, Part};
+use std::path::PathBuf;
+
+/// An example of the puzzle text, with the answers the text gives for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// `example`, `example-2`... in the order of the text, like the example inputs in `inputs/dayNN`.
+    pub name: String,
+    pub input: String,
+    pub answers: Answers,
+}
+
+/// Where the puzzle text of a day is: the doc comments of its crate.
+pub fn source_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("day{:02}/src/lib.rs", day))
+}
+
+/// The examples of a day's puzzle text, none if the crate doesn't have the text.
+pub fn doc_examples(day: u8) -> Result<Vec<Example>, String> {
+    let path = source_path(day);
+    let source =
+        std::fs::read_to_string(&path).map_err(|error| format!("cannot read {}: {}", path.display(), error))?;
+    extract(&source).map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+/// Pulls the examples out of the doc comments of some source. An example is a fenced block whose info string has
+/// `example`, followed by the answers of the parts it has:
+///
+/// ````text
+/// /// ```text example part1:7 part2:5
+/// /// 199
+/// /// 200
+/// /// ```
+/// ````
+///
+/// Rustdoc shows it as text and doesn't run it. Other fenced blocks are skipped. Inputs are normalized like any other.
+pub fn extract(source: &str) -> Result<Vec<Example>, String> {
+    let mut examples = Vec::new();
+    // The line the open fence starts on, if any, with the answers and the lines so far when it is an example
+    let mut fence: Option<(usize, Option<Open>)> = None;
+    for (i, line) in source.lines().enumerate() {
+        let Some(doc) = line.trim_start().strip_prefix("///") else {
+            if let Some((start, _)) = fence {
+                return Err(format!("line {}: the fenced block is never closed", start + 1));
+            }
+            continue;
+        };
+        let doc = doc.strip_prefix(' ').unwrap_or(doc);
+        match &mut fence {
+            None => {
+                if let Some(info) = doc.trim_end().strip_prefix("```") {
+                    let example = info
+                        .split([' ', ','])
+                        .any(|word| word == "example")
+                        .then(|| answers(info).map(|answers| (answers, Vec::new())))
+                        .transpose()
+                        .map_err(|error| format!("line {}: {}", i + 1, error))?;
+                    fence = Some((i, example));
+                }
+            }
+            Some((_, example)) if doc.trim_end() == "```" => {
+                if let Some((answers, lines)) = example.take() {
+                    let name = match examples.len() {
+                        0 => "example".to_string(),
+                        n => format!("example-{}", n + 1),
+                    };
+                    examples.push(Example {
+                        name,
+                        input: inpututils::normalize(&lines.join("\n")),
+                        answers,
+                    });
+                }
+                fence = None;
+            }
+            Some((_, Some((_, lines)))) => lines.push(doc),
+            Some((_, None)) => {}
+        }
+    }
+    match fence {
+        Some((start, _)) => Err(format!("line {}: the fenced block is never closed", start + 1)),
+        None => Ok(examples),
+    }
+}
+
+/// An example being read: its answers and its lines so far.
+type Open<'a> = (Answers, Vec<&'a str>);
+
+/// Reads the `part1:...` and `part2:...` of an info string.
+fn answers(info: &str) -> Result<Answers, String> {
+    let mut answers = Answers::default();
+    for (key, value) in info.split([' ', ',']).filter_map(|word| word.split_once(':')) {
+        let part = match key {
+            "part1" => Part::One,
+            "part2" => Part::Two,
+            _ => return Err(format!("unknown `{}` in the example", key)),
+        };
+        answers.set(part, Answer::from(value));
+    }
+    Ok(answers)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::Answers;
+    use crate::examples::{extract, Example};
+    use puzzle::Answer;
+
+    #[test]
+    fn test_extract() {
+        let source = "\
+/// For example:
+///
+/// ```text example part1:7 part2:5
+/// 199
+///
+///  200
+/// ```
+///
+/// ```
+/// let ignored = true;
+/// ```
+/// ```text,example,part2:abc
+/// x
+/// ```
+pub fn part1() {}
+";
+        assert_eq!(
+            extract(source),
+            Ok(vec![
+                Example {
+                    name: "example".to_string(),
+                    input: "199\n\n 200".to_string(),
+                    answers: Answers {
+                        part1: Some(Answer::Integer(7)),
+                        part2: Some(Answer::Integer(5)),
+                        wrong: Vec::new(),
+                    },
+                },
+                Example {
+                    name: "example-2".to_string(),
+                    input: "x".to_string(),
+                    answers: Answers {
+                        part1: None,
+                        part2: Some(Answer::from("abc")),
+                        wrong: Vec::new(),
+                    },
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_extract_errors() {
+        assert_eq!(
+            extract("/// ```text example\n/// 1\nfn main() {}\n"),
+            Err("line 1: the fenced block is never closed".to_string())
+        );
+        assert_eq!(
+            extract("/// text\n/// ```text example part3:1\n/// 1\n/// ```\n"),
+            Err("line 2: unknown `part3` in the example".to_string())
+        );
+        assert_eq!(extract("fn main() {}\n"), Ok(Vec::new()));
+    }
+}
@@ -0,0 +1,300 @@
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The puzzle server, where inputs are downloaded from and answers submitted to.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Local settings of `fetch`, kept out of git since the session token gives access to the account.
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "http://localhost:8080"
+/// rate_limit = "5s"
+/// ```
+///
+/// `AOC_SESSION` and `AOC_BASE_URL` take precedence over the file.
+pub const CONFIG: &str = "aoc.toml";
+
+/// Where the time of the last request is kept, so the rate limit holds across runs.
+pub const STAMP: &str = "inputs/.last-fetch";
+
+/// Minimum time between two requests, to go easy on the server.
+pub const RATE_LIMIT: Duration = Duration::from_secs(5);
+
+pub const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    session: Option<String>,
+    base_url: Option<String>,
+    rate_limit: Option<String>,
+}
+
+/// Downloads puzzle inputs, and submits answers, with the session cookie of an account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fetcher {
+    pub base_url: String,
+    pub session: String,
+    pub rate_limit: Duration,
+    pub stamp: PathBuf,
+}
+
+/// What [Fetcher::fetch] did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already there.
+    Cached,
+    Downloaded,
+}
+
+impl Fetcher {
+    /// Settings from [CONFIG] and the environment.
+    pub fn from_config() -> Result<Fetcher, String> {
+        let config = match fs::read_to_string(CONFIG) {
+            Ok(config) => toml::from_str(&config).map_err(|error| format!("invalid {}: {}", CONFIG, error))?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Config::default(),
+            Err(error) => return Err(format!("cannot read {}: {}", CONFIG, error)),
+        };
+        let session = std::env::var("AOC_SESSION")
+            .ok()
+            .or(config.session)
+            .filter(|session| !session.trim().is_empty())
+            .ok_or_else(|| format!("no session token, set AOC_SESSION or `session` in {}", CONFIG))?;
+        let base_url = std::env::var("AOC_BASE_URL")
+            .ok()
+            .or(config.base_url)
+            .unwrap_or_else(|| BASE_URL.to_string());
+        let rate_limit = match config.rate_limit {
+            Some(rate_limit) => {
+                parse_rate_limit(&rate_limit).map_err(|error| format!("invalid rate_limit in {}: {}", CONFIG, error))?
+            }
+            None => RATE_LIMIT,
+        };
+        Ok(Fetcher {
+            base_url,
+            session: session.trim().to_string(),
+            rate_limit,
+            stamp: PathBuf::from(STAMP),
+        })
+    }
+
+    pub fn url(&self, day: u8) -> String {
+        format!("{}/2021/day/{}/input", self.base_url.trim_end_matches('/'), day)
+    }
+
+    /// Downloads the input of a day to `path`, unless it is already there or `force` says to download it again.
+    pub fn fetch(&self, day: u8, path: &Path, force: bool) -> Result<Fetched, String> {
+        if path.is_file() && !force {
+            return Ok(Fetched::Cached);
+        }
+        let input = self.download(day)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|error| format!("cannot create {}: {}", dir.display(), error))?;
+        }
+        fs::write(path, input).map_err(|error| format!("cannot write {}: {}", path.display(), error))?;
+        Ok(Fetched::Downloaded)
+    }
+
+    /// Downloads the input of a day, waiting first if the last request was too recent.
+    pub fn download(&self, day: u8) -> Result<String, String> {
+        self.wait_for_rate_limit();
+        let url = self.url(day);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call();
+        self.record_request();
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|error| format!("cannot read the input from {}: {}", url, error)),
+            Err(ureq::Error::Status(status, response)) => {
+                let message = response.into_string().unwrap_or_default();
+                let hint = match status {
+                    400 | 500 => " (is the session token still valid?)",
+                    404 => " (is the day unlocked yet?)",
+                    _ => "",
+                };
+                Err(format!("{} answered {}{}: {}", url, status, hint, message.trim()))
+            }
+            Err(error) => Err(format!("cannot download {}: {}", url, error)),
+        }
+    }
+
+    /// Waits until [Fetcher::rate_limit] has passed since the last request to the server.
+    pub fn wait_for_rate_limit(&self) {
+        if let Some(last) = self.last_request() {
+            let elapsed = last.elapsed().unwrap_or_default();
+            if elapsed < self.rate_limit {
+                thread::sleep(self.rate_limit - elapsed);
+            }
+        }
+    }
+
+    pub fn record_request(&self) {
+        // Not being able to record it only weakens the rate limit
+        if let Some(dir) = self.stamp.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        let _ = fs::write(&self.stamp, format!("{}\n", now.as_micros()));
+    }
+
+    /// When the last request was made, as recorded in [Fetcher::stamp]. The time of the last change of the file is
+    /// rounded to the tick of a coarse clock on some systems, so it is only used for stamps without a time in them.
+    fn last_request(&self) -> Option<SystemTime> {
+        let recorded = fs::read_to_string(&self.stamp).ok()?;
+        match recorded.trim().parse::<u64>() {
+            Ok(micros) => Some(UNIX_EPOCH + Duration::from_micros(micros)),
+            Err(_) => fs::metadata(&self.stamp).and_then(|stamp| stamp.modified()).ok(),
+        }
+    }
+}
+
+/// Parses a duration like `500ms`, `10s` or `2m`, or a number of seconds. Zero turns the rate limit off.
+pub fn parse_rate_limit(rate_limit: &str) -> Result<Duration, String> {
+    if rate_limit.trim().parse::<u64>() == Ok(0) {
+        Ok(Duration::ZERO)
+    } else {
+        crate::runner::parse_timeout(rate_limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fetch::{Fetched, Fetcher};
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    /// Stands in for the puzzle server: answers every request with `status` and `body`, and sends back the request line
+    /// and cookie it got.
+    fn serve(status: u16, body: &'static str) -> (String, mpsc::Receiver<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, requests) = mpsc::channel();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                reader.read_line(&mut request).unwrap();
+                let mut cookie = String::new();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some(value) = header.strip_prefix("Cookie: ").or(header.strip_prefix("cookie: ")) {
+                        cookie = value.trim().to_string();
+                    }
+                }
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                if sender.send((request.trim().to_string(), cookie)).is_err() {
+                    break;
+                }
+            }
+        });
+        (base_url, requests)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn fetcher(base_url: String, dir: &std::path::Path, rate_limit: Duration) -> Fetcher {
+        Fetcher {
+            base_url,
+            session: "secret".to_string(),
+            rate_limit,
+            stamp: dir.join(".last-fetch"),
+        }
+    }
+
+    #[test]
+    fn test_fetch() {
+        let (base_url, requests) = serve(200, "199\n200\n208\n");
+        let dir = temp_dir("cache");
+        let fetcher = fetcher(base_url, &dir, Duration::ZERO);
+        let path = dir.join("day01/input.txt");
+
+        assert_eq!(fetcher.fetch(1, &path, false), Ok(Fetched::Downloaded));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "199\n200\n208\n");
+        assert_eq!(
+            requests.recv().unwrap(),
+            (
+                "GET /2021/day/1/input HTTP/1.1".to_string(),
+                "session=secret".to_string()
+            )
+        );
+
+        assert_eq!(fetcher.fetch(1, &path, false), Ok(Fetched::Cached));
+        assert_eq!(fetcher.fetch(1, &path, true), Ok(Fetched::Downloaded));
+        assert_eq!(requests.try_iter().count(), 1);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (base_url, _requests) = serve(404, "Please don't repeatedly request this endpoint before it unlocks!");
+        let dir = temp_dir("errors");
+        let fetcher = fetcher(base_url.clone(), &dir, Duration::ZERO);
+        let path = dir.join("day25/input.txt");
+        assert_eq!(
+            fetcher.fetch(25, &path, false),
+            Err(format!(
+                "{}/2021/day/25/input answered 404 (is the day unlocked yet?): Please don't repeatedly request this \
+                 endpoint before it unlocks!",
+                base_url
+            ))
+        );
+        assert!(!path.exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let (base_url, _requests) = serve(200, "3,4,3,1,2");
+        let dir = temp_dir("rate-limit");
+        let fetcher = fetcher(base_url, &dir, Duration::from_millis(300));
+        let start = Instant::now();
+        fetcher.download(6).unwrap();
+        fetcher.download(6).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_url() {
+        let fetcher = fetcher(
+            "http://localhost:8080/".to_string(),
+            std::path::Path::new("."),
+            Duration::ZERO,
+        );
+        assert_eq!(fetcher.url(7), "http://localhost:8080/2021/day/7/input");
+    }
+
+    #[test]
+    fn test_parse_rate_limit() {
+        assert_eq!(crate::fetch::parse_rate_limit("0"), Ok(Duration::ZERO));
+        assert_eq!(crate::fetch::parse_rate_limit("2s"), Ok(Duration::from_secs(2)));
+        assert!(crate::fetch::parse_rate_limit("soon").is_err());
+    }
+}
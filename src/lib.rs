@@ -0,0 +1,92 @@
+pub mod answers;
+pub mod crosscheck;
+pub mod examples;
+pub mod fetch;
+pub mod output;
+pub mod pool;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
+pub mod timing;
+
+use puzzle::Puzzle;
+use std::path::{Path, PathBuf};
+
+/// Every solved day, in calendar order. Adding a day is adding it here.
+pub static DAYS: &[&dyn Puzzle] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+];
+
+pub fn find_day(day: u8) -> Option<&'static dyn Puzzle> {
+    DAYS.iter().find(|puzzle| puzzle.day() == day).copied()
+}
+
+/// Where the puzzle inputs and their answers live: `inputs/dayNN/<name>.txt`.
+pub const INPUTS: &str = "inputs";
+
+/// Name of the puzzle input of our own account, as opposed to the examples or other accounts' inputs.
+pub const DEFAULT_INPUT: &str = "input";
+
+pub fn input_dir(day: u8) -> PathBuf {
+    Path::new(INPUTS).join(format!("day{:02}", day))
+}
+
+pub fn input_path(day: u8, name: &str) -> PathBuf {
+    input_dir(day).join(format!("{}.txt", name))
+}
+
+/// Names of every input of a day, like `input`, `example` or `example-2`, sorted.
+pub fn input_names(day: u8) -> Vec<String> {
+    let mut names = std::fs::read_dir(input_dir(day))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.is_file() && path.extension().is_some_and(|extension| extension == "txt"))
+                .filter_map(|path| path.file_stem().and_then(|name| name.to_str()).map(String::from))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(crate::input_path(7, "example"), Path::new("inputs/day07/example.txt"));
+    }
+
+    #[test]
+    fn test_input_names() {
+        assert_eq!(
+            crate::input_names(12),
+            vec!["example", "example-2", "example-3", "input"]
+        );
+        assert!(crate::input_names(99).is_empty());
+    }
+}
@@ -1,189 +1,668 @@
-fn main() {
-    println!("Day 1 - Part 1: {}", day01::part1(include_str!("../inputs/day01")));
-    println!("Day 1 - Part 2: {}", day01::part2(include_str!("../inputs/day01")));
-
-    println!("Day 2 - Part 1: {}", day02::part1(include_str!("../inputs/day02")));
-    println!("Day 2 - Part 2: {}", day02::part2(include_str!("../inputs/day02")));
-
-    let day3_input = inpututils::read_lines("inputs/day03");
-    println!("Day 3 - Part 1: {}", day03::part1(&day3_input));
-    println!("Day 3 - Part 2: {}", day03::part2(&day3_input));
-
-    let day4_input = inpututils::read_lines("inputs/day04");
-    println!("Day 4 - Part 1: {}", day04::part1(&day4_input));
-    println!("Day 4 - Part 2: {}", day04::part2(&day4_input));
-
-    let day5_input = inpututils::read_lines("inputs/day05");
-    println!("Day 5 - Part 1: {}", day05::part1(&day5_input));
-    println!("Day 5 - Part 2: {}", day05::part2(&day5_input));
+mod cli;
+
+use advent_of_code_2021::answers::{self, Answers, Verdict, WrongAnswer};
+use advent_of_code_2021::crosscheck::{self, SIZES};
+use advent_of_code_2021::examples::{self, doc_examples};
+use advent_of_code_2021::fetch::{Fetched, Fetcher};
+use advent_of_code_2021::output::{self, Format, Status};
+use advent_of_code_2021::pool;
+use advent_of_code_2021::runner::{
+    run_day, run_day_with_parallel_parts, run_day_with_timeout, select_days, Input, RunDay,
+};
+use advent_of_code_2021::scaffold;
+use advent_of_code_2021::submit::Outcome;
+use advent_of_code_2021::{find_day, input_names, input_path, timing, DEFAULT_INPUT};
+use clap::Parser;
+use cli::{
+    Cli, Command, CrosscheckArgs, ExamplesArgs, FetchArgs, GenerateArgs, NewDayArgs, RunArgs, SubmitArgs, VerifyArgs,
+};
+use puzzle::{Answer, Part, Puzzle};
+use std::io;
+use std::ops::RangeInclusive;
+use std::panic;
+use std::path::Path;
+use std::process;
+use std::time::Instant;
 
-    let day6_input = inpututils::read_comma_separated_as::<u8>("inputs/day06");
-    println!("Day 6 - Part 1: {}", day06::part1(&day6_input));
-    println!("Day 6 - Part 2: {}", day06::part2(&day6_input));
-
-    let day7_input = inpututils::read_comma_separated_as::<u64>("inputs/day07");
-    let mut day7_pt1_input = day7_input.to_vec();
-    println!("Day 7 - Part 1: {}", day07::part1(&mut day7_pt1_input));
-    println!("Day 7 - Part 2: {}", day07::part2(&day7_input));
+fn main() {
+    let cli = Cli::parse();
+    match cli.command.unwrap_or(Command::Run(RunArgs::default())) {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Generate(args) => generate(args),
+        Command::Crosscheck(args) => crosscheck(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::NewDay(args) => new_day(args),
+        Command::Examples(args) => write_examples(args),
+    }
+}
 
-    println!("Day 8 - Part 1: {}", day08::part1(include_str!("../inputs/day08")));
-    println!("Day 8 - Part 2: {}", day08::part2(include_str!("../inputs/day08")));
+/// The registered days among `--day` or `--days`, every day without them.
+fn days(day: Option<u8>, days: Option<RangeInclusive<u8>>) -> Vec<&'static dyn Puzzle> {
+    let range = match (day, days) {
+        (Some(day), _) => day..=day,
+        (None, Some(days)) => days,
+        (None, None) => 1..=25,
+    };
+    let days = select_days(range.clone());
+    if days.is_empty() {
+        eprintln!("error: no solution for days {}..={}", range.start(), range.end());
+        process::exit(2);
+    }
+    days
+}
 
-    println!("Day 9 - Part 1: {}", day09::part1(include_str!("../inputs/day09")));
-    println!("Day 9 - Part 2: {}", day09::part2(include_str!("../inputs/day09")));
+fn run(args: RunArgs) {
+    let days = days(args.day, args.days);
+    let parts = args.part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+    let input = match (args.input.as_deref(), args.name) {
+        (Some(input), _) => Input::from_arg(input),
+        (None, name) => Input::Named(name.unwrap_or_else(|| DEFAULT_INPUT.to_string())),
+    };
+
+    let repeat = args.repeat.map_or(1, usize::from);
+    let threads = if args.parallel || args.parallel_parts || args.jobs.is_some() {
+        args.jobs.map_or_else(pool::available_threads, usize::from)
+    } else {
+        1
+    };
+
+    let start = Instant::now();
+    let mut inputs = Vec::new();
+    for puzzle in days {
+        let day = puzzle.day();
+        // Only some days have an input of a given name, like a second example, or even their own input yet. A day
+        // asked for by itself must have the input though.
+        if let Input::Named(name) = &input {
+            let path = input_path(day, name);
+            if !path.is_file() {
+                if args.day.is_some() {
+                    eprintln!("error: day {} has no input at {}", day, path.display());
+                    process::exit(1);
+                }
+                eprintln!("Day {}: no input at {}, skipped", day, path.display());
+                continue;
+            }
+        }
+        let input = input.load(day).unwrap_or_else(|error| {
+            eprintln!("error: cannot read input for day {}: {}", day, error);
+            process::exit(1);
+        });
+        inputs.push((puzzle, input));
+    }
 
-    println!("Day 10 - Part 1: {}", day10::part1(include_str!("../inputs/day10")));
-    println!("Day 10 - Part 2: {}", day10::part2(include_str!("../inputs/day10")));
+    // A bad input, a panic or a timeout is reported and skipped, so one broken day doesn't hide the answers of the
+    // others.
+    let mut failed = false;
+    let mut reports = Vec::new();
+    let run_one: RunDay = if args.parallel_parts {
+        run_day_with_parallel_parts
+    } else {
+        run_day
+    };
+    let solve = |&(puzzle, ref input): &(&'static dyn Puzzle, String)| match args.timeout {
+        Some(timeout) => run_day_with_timeout(puzzle, input, &parts, repeat, timeout, run_one),
+        None => run_one(puzzle, input, &parts, repeat),
+    };
+    pool::run_ordered(&inputs, threads, solve, |report| {
+        let day = report.day;
+        match &report.parts {
+            Ok(parts) => {
+                for part in parts {
+                    match &part.answer {
+                        Ok(answer) => {
+                            if args.format == Format::Text {
+                                println!("Day {} - Part {}: {}", day, part.part, answer)
+                            }
+                        }
+                        Err(error) => {
+                            eprintln!("error: {} (part {})", error, part.part);
+                            failed = true;
+                        }
+                    }
+                }
+            }
+            Err(error) => {
+                eprintln!("error: {}", error);
+                failed = true;
+            }
+        }
+        reports.push(report);
+    });
+    let wall = start.elapsed();
+
+    // Records already carry the timings, the table is only for humans.
+    let written = match args.format {
+        Format::Text => {
+            if args.time || args.repeat.is_some() {
+                println!();
+                print!("{}", timing::report(&reports, repeat));
+                print!("{}", timing::wall_clock_summary(wall, &reports, threads));
+            }
+            Ok(())
+        }
+        Format::Json => output::write_json(&output::records(&reports, &parts), io::stdout().lock()),
+        Format::Csv => output::write_csv(&output::records(&reports, &parts), io::stdout().lock()),
+    };
+    if let Err(error) = written {
+        eprintln!("error: cannot write the results: {}", error);
+        process::exit(1);
+    }
+    if failed {
+        let failures = reports
+            .iter()
+            .map(|report| (report.day, Status::of_day(report)))
+            .filter(|&(_, status)| status != Status::Ok)
+            .map(|(day, status)| format!("day {} ({})", day, status))
+            .collect::<Vec<_>>();
+        eprintln!(
+            "error: {} of {} days failed: {}",
+            failures.len(),
+            reports.len(),
+            failures.join(", ")
+        );
+        process::exit(1);
+    }
+}
 
-    println!("Day 11 - Part 1: {}", day11::part1(include_str!("../inputs/day11")));
-    println!("Day 11 - Part 2: {}", day11::part2(include_str!("../inputs/day11")));
+fn verify(args: VerifyArgs) {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for puzzle in days(args.day, args.days) {
+        let day = puzzle.day();
+        let mut verified = false;
+        for name in input_names(day) {
+            let input_file = input_path(day, &name);
+            let answers = match Answers::load(&input_file) {
+                Ok(Some(answers)) => answers,
+                Ok(None) => continue,
+                Err(error) => {
+                    eprintln!("error: {}", error);
+                    failed += Part::ALL.len();
+                    continue;
+                }
+            };
+            let input = match inpututils::read_file(&input_file) {
+                Ok(input) => input,
+                Err(error) => {
+                    eprintln!("error: cannot read {}: {}", input_file.display(), error);
+                    failed += Part::ALL.len();
+                    continue;
+                }
+            };
+            verified = true;
+            let report = run_day(puzzle, &input, &Part::ALL, 1);
+            for (part, verdict) in answers::verify(&report, &Part::ALL, &answers) {
+                match verdict {
+                    Verdict::Pass => passed += 1,
+                    Verdict::Fail { .. } => failed += 1,
+                    Verdict::Missing { .. } => missing += 1,
+                }
+                println!("Day {} - Part {} ({}): {}", day, part, name, verdict);
+            }
+        }
+        if !verified {
+            println!("Day {}: no input with recorded answers", day);
+            missing += Part::ALL.len();
+        }
+    }
+    println!();
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    if failed > 0 {
+        process::exit(1);
+    }
+}
 
-    println!("Day 12 - Part 1: {}", day12::part1(include_str!("../inputs/day12")));
-    println!("Day 12 - Part 2: {}", day12::part2(include_str!("../inputs/day12")));
+fn generate(args: GenerateArgs) {
+    let Some(generator) = inputgen::find_generator(args.day) else {
+        eprintln!("error: day {} has no input generator", args.day);
+        process::exit(2);
+    };
+    let size = args.size.unwrap_or(generator.default_size);
+    print!("{}", generator.generate(size, args.seed));
+}
 
-    println!("Day 13 - Part 1: {}", day13::part1(include_str!("../inputs/day13")));
-    println!("Day 13 - Part 2: {}", day13::part2(include_str!("../inputs/day13")));
+fn crosscheck(args: CrosscheckArgs) {
+    // Panics are reported with the counterexample, and shrinking it can panic many times over
+    panic::set_hook(Box::new(|_| {}));
+    let mut mismatches = 0;
+    for puzzle in days(args.day, args.days) {
+        let Some(generator) = inputgen::find_generator(puzzle.day()) else {
+            if args.day.is_some() {
+                eprintln!(
+                    "error: day {} has no input generator to cross-check it with",
+                    puzzle.day()
+                );
+                process::exit(2);
+            }
+            eprintln!("Day {}: no input generator, not cross-checked", puzzle.day());
+            continue;
+        };
+        let parsed = puzzle.parse(&generator.generate(1, 0));
+        if parsed.map_or(true, |parsed| parsed.variants().is_empty()) {
+            continue;
+        }
+        let found = crosscheck::cross_check(puzzle, generator, &SIZES, 0..args.seeds);
+        if found.is_empty() {
+            println!("Day {}: variants agree", puzzle.day());
+        }
+        for mismatch in &found {
+            println!("{}", mismatch);
+        }
+        mismatches += found.len();
+    }
+    if mismatches > 0 {
+        process::exit(1);
+    }
+}
 
-    println!("Day 14 - Part 1: {}", day14::part1(include_str!("../inputs/day14")));
-    println!("Day 14 - Part 2: {}", day14::part2(include_str!("../inputs/day14")));
+fn fetch(args: FetchArgs) {
+    let mut fetcher = Fetcher::from_config().unwrap_or_else(|error| {
+        eprintln!("error: {}", error);
+        process::exit(2);
+    });
+    if let Some(base_url) = args.base_url {
+        fetcher.base_url = base_url;
+    }
+    // Unlike the other commands, this works for days without a solution yet
+    let range = match (args.day, args.days) {
+        (Some(day), _) => day..=day,
+        (None, Some(days)) => days,
+        (None, None) => 1..=25,
+    };
+    let mut failed = false;
+    for day in range {
+        let path = input_path(day, DEFAULT_INPUT);
+        match fetcher.fetch(day, &path, args.force) {
+            Ok(Fetched::Cached) => println!("Day {}: {} already there", day, path.display()),
+            Ok(Fetched::Downloaded) => println!("Day {}: downloaded to {}", day, path.display()),
+            Err(error) => {
+                eprintln!("error: day {}: {}", day, error);
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
 
-    println!("Day 15 - Part 1: {}", day15::part1(include_str!("../inputs/day15")));
-    println!("Day 15 - Part 2: {}", day15::part2(include_str!("../inputs/day15")));
+fn submit(args: SubmitArgs) {
+    let fail = |error: String| -> ! {
+        eprintln!("error: {}", error);
+        process::exit(1);
+    };
+    let (day, part) = (args.day, args.part);
+    let path = input_path(day, DEFAULT_INPUT);
+    let mut answers = Answers::load(&path)
+        .unwrap_or_else(|error| fail(error))
+        .unwrap_or_default();
+    let answer = match args.answer {
+        Some(answer) => Answer::from(answer),
+        None => {
+            let Some(puzzle) = find_day(day) else {
+                eprintln!("error: no solution for day {}, give the answer with --answer", day);
+                process::exit(2);
+            };
+            let input = inpututils::read_file(&path)
+                .unwrap_or_else(|error| fail(format!("cannot read {}: {}", path.display(), error)));
+            let report = run_day(puzzle, &input, &[part], 1);
+            let answer = report.parts.and_then(|parts| parts[0].answer.clone());
+            let answer = answer.unwrap_or_else(|error| fail(format!("no answer to submit: {}", error)));
+            // A drawing has to be read by a human, the site only takes the letters it shows.
+            if let Answer::Grid(_) = answer {
+                eprintln!(
+                    "error: day {} part {} draws its answer, read it and give it with --answer:\n{}",
+                    day, part, answer
+                );
+                process::exit(2);
+            }
+            answer
+        }
+    };
+    if answers.get(part) == Some(&answer) {
+        println!("Day {} - Part {}: {} is already known to be right", day, part, answer);
+        return;
+    }
+    if let Some(reason) = answers.rejects(part, &answer) {
+        fail(format!(
+            "not submitting {} for day {} part {}: {}",
+            answer, day, part, reason
+        ));
+    }
 
-    println!("Day 16 - Part 1: {}", day16::part1(include_str!("../inputs/day16")));
-    println!("Day 16 - Part 2: {}", day16::part2(include_str!("../inputs/day16")));
+    let mut fetcher = Fetcher::from_config().unwrap_or_else(|error| {
+        eprintln!("error: {}", error);
+        process::exit(2);
+    });
+    if let Some(base_url) = args.base_url {
+        fetcher.base_url = base_url;
+    }
+    let outcome = fetcher.submit(day, part, &answer).unwrap_or_else(|error| fail(error));
+    println!("Day {} - Part {}: {}: {}", day, part, answer, outcome);
+    match outcome {
+        Outcome::Right => answers.set(part, answer),
+        Outcome::Wrong { hint } => answers.wrong.push(WrongAnswer {
+            part: part.number(),
+            answer,
+            hint,
+        }),
+        _ => process::exit(1),
+    }
+    answers.save(&path).unwrap_or_else(|error| fail(error));
+    if answers.get(part).is_none() {
+        process::exit(1);
+    }
+}
 
-    println!("Day 17 - Part 1: {}", day17::part1(include_str!("../inputs/day17")));
-    println!("Day 17 - Part 2: {}", day17::part2(include_str!("../inputs/day17")));
+fn new_day(args: NewDayArgs) {
+    match scaffold::new_day(Path::new("."), args.day) {
+        Ok(files) => {
+            for file in files {
+                println!("{}", file.display());
+            }
+            println!();
+            println!(
+                "Get the input with `fetch --day {}`, then record the answers of the example.",
+                args.day
+            );
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            process::exit(1);
+        }
+    }
+}
 
-    println!("Day 18 - Part 1: {}", day18::part1(include_str!("../inputs/day18")));
-    println!("Day 18 - Part 2: {}", day18::part2(include_str!("../inputs/day18")));
+fn write_examples(args: ExamplesArgs) {
+    let fail = |error: String| -> ! {
+        eprintln!("error: {}", error);
+        process::exit(1);
+    };
+    for puzzle in days(args.day, args.days) {
+        let day = puzzle.day();
+        let found = doc_examples(day).unwrap_or_else(|error| fail(error));
+        if found.is_empty() {
+            println!("Day {}: no example in {}", day, examples::source_path(day).display());
+        }
+        for example in found {
+            let answers = Part::ALL
+                .iter()
+                .filter_map(|&part| {
+                    example
+                        .answers
+                        .get(part)
+                        .map(|answer| format!("part {} = {}", part, answer))
+                })
+                .collect::<Vec<_>>();
+            println!("Day {}: {} ({})", day, example.name, answers.join(", "));
+            if args.write {
+                let path = input_path(day, &example.name);
+                if let Some(dir) = path.parent() {
+                    std::fs::create_dir_all(dir)
+                        .unwrap_or_else(|error| fail(format!("cannot create {}: {}", dir.display(), error)));
+                }
+                std::fs::write(&path, format!("{}\n", example.input))
+                    .unwrap_or_else(|error| fail(format!("cannot write {}: {}", path.display(), error)));
+                // Answers known to be wrong are kept
+                let mut answers = Answers::load(&path)
+                    .unwrap_or_else(|error| fail(error))
+                    .unwrap_or_default();
+                answers.part1 = example.answers.part1;
+                answers.part2 = example.answers.part2;
+                answers.save(&path).unwrap_or_else(|error| fail(error));
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use advent_of_code_2021::answers::Answers;
+    use advent_of_code_2021::answers::{self, Verdict};
+    use advent_of_code_2021::crosscheck::{self, SIZES};
+    use advent_of_code_2021::examples::doc_examples;
+    use advent_of_code_2021::runner::run_day;
+    use advent_of_code_2021::{find_day, input_names, input_path, DAYS, DEFAULT_INPUT};
+    use puzzle::Part;
+
+    /// Solves a day's input and checks both parts against the answers recorded next to it, right and wrong.
+    fn check(day: u8) {
+        let puzzle = find_day(day).unwrap();
+        let path = input_path(day, DEFAULT_INPUT);
+        if !path.is_file() {
+            panic!("{} is missing", path.display());
+        }
+        let input = inpututils::read_file(&path).unwrap();
+        let answers = Answers::load(&path).unwrap().unwrap();
+        let parsed = puzzle.parse(&input).unwrap();
+        for wrong in &answers.wrong {
+            let part = Part::ALL[usize::from(wrong.part) - 1];
+            assert_ne!(
+                parsed.solve(part),
+                Ok(wrong.answer.clone()),
+                "part {} was rejected",
+                part
+            );
+        }
+        assert_eq!(parsed.part1(), Ok(answers.part1.unwrap()));
+        assert_eq!(parsed.part2(), Ok(answers.part2.unwrap()));
+    }
+
+    #[test]
+    fn test_days_are_registered_in_order() {
+        let days = advent_of_code_2021::DAYS
+            .iter()
+            .map(|puzzle| puzzle.day())
+            .collect::<Vec<_>>();
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", days);
+    }
+
+    /// Every other input, like the examples, must match the answers recorded for it.
+    #[test]
+    fn test_other_inputs() {
+        for puzzle in DAYS {
+            let day = puzzle.day();
+            for name in input_names(day).into_iter().filter(|name| name != DEFAULT_INPUT) {
+                let path = input_path(day, &name);
+                let Some(answers) = Answers::load(&path).unwrap() else {
+                    continue;
+                };
+                let input = inpututils::read_file(&path).unwrap();
+                let report = run_day(*puzzle, &input, &Part::ALL, 1);
+                for (part, verdict) in answers::verify(&report, &Part::ALL, &answers) {
+                    assert!(
+                        matches!(verdict, Verdict::Pass | Verdict::Missing { .. }),
+                        "day {} part {} ({}): {}",
+                        day,
+                        part,
+                        name,
+                        verdict
+                    );
+                }
+            }
+        }
+    }
 
     #[test]
     fn test_day_01() {
-        assert_eq!(day01::part1(include_str!("../inputs/day01")), 1482);
-        assert_eq!(day01::part2(include_str!("../inputs/day01")), 1518);
+        check(1);
     }
 
     #[test]
     fn test_day_02() {
-        assert_eq!(day02::part1(include_str!("../inputs/day02")), 1427868);
-        assert_eq!(day02::part2(include_str!("../inputs/day02")), 1568138742);
+        check(2);
     }
 
     #[test]
     fn test_day_03() {
-        let day3_input = inpututils::read_lines("inputs/day03");
-        assert_eq!(day03::part1(&day3_input), 3895776);
-        assert_eq!(day03::part2(&day3_input), 7928162);
+        check(3);
     }
 
     #[test]
     fn test_day_04() {
-        let input = inpututils::read_lines("inputs/day04");
-        assert_eq!(day04::part1(&input), 8442);
-        assert_eq!(day04::part2(&input), 4590);
+        check(4);
     }
 
     #[test]
     fn test_day_05() {
-        let input = inpututils::read_lines("inputs/day05");
-        assert_eq!(day05::part1(&input), 7436);
-        assert_eq!(day05::part2(&input), 21104);
+        check(5);
     }
 
     #[test]
     fn test_day_06() {
-        let input = inpututils::read_comma_separated_as::<u8>("inputs/day06");
-        assert_eq!(day06::part1(&input), 386640);
-        assert_eq!(day06::part2(&input), 1733403626279);
+        check(6);
     }
 
     #[test]
     fn test_day_07() {
-        let input = inpututils::read_comma_separated_as::<u64>("inputs/day07");
-        let mut pt1_input = input.to_vec();
-        assert_eq!(day07::part1(&mut pt1_input), 352997);
-        assert_eq!(day07::part2(&input), 101571302);
+        check(7);
     }
 
     #[test]
     fn test_day_08() {
-        assert_eq!(day08::part1(include_str!("../inputs/day08")), 274);
-        assert_eq!(day08::part2(include_str!("../inputs/day08")), 1012089);
+        check(8);
     }
 
     #[test]
     fn test_day_09() {
-        assert_eq!(day09::part1(include_str!("../inputs/day09")), 465);
-        assert_eq!(day09::part2(include_str!("../inputs/day09")), 1269555);
+        check(9);
     }
 
     #[test]
     fn test_day_10() {
-        assert_eq!(day10::part1(include_str!("../inputs/day10")), 168417);
-        assert_eq!(day10::part2(include_str!("../inputs/day10")), 2802519786);
+        check(10);
     }
 
     #[test]
     fn test_day_11() {
-        assert_eq!(day11::part1(include_str!("../inputs/day11")), 1594);
-        assert_eq!(day11::part2(include_str!("../inputs/day11")), 437);
+        check(11);
     }
 
     #[test]
     fn test_day_12() {
-        assert_eq!(day12::part1(include_str!("../inputs/day12")), 4011);
-        assert_eq!(day12::part2(include_str!("../inputs/day12")), 108035);
+        check(12);
     }
 
     #[test]
     fn test_day_13() {
-        assert_eq!(day13::part1(include_str!("../inputs/day13")), 729);
-        assert_eq!(
-            day13::part2(include_str!("../inputs/day13")),
-            "\
-        ###...##..####.#....###..#..#.####.###..\n\
-        #..#.#..#....#.#....#..#.#..#.#....#..#.\n\
-        #..#.#......#..#....###..####.###..#..#.\n\
-        ###..#.##..#...#....#..#.#..#.#....###..\n\
-        #.#..#..#.#....#....#..#.#..#.#....#....\n\
-        #..#..###.####.####.###..#..#.#....#....\
-        "
-        );
+        check(13);
     }
 
     #[test]
     fn test_day_14() {
-        assert_eq!(day14::part1(include_str!("../inputs/day14")), 2602);
-        assert_eq!(day14::part2(include_str!("../inputs/day14")), 2942885922173);
+        check(14);
     }
 
     #[test]
     fn test_day_15() {
-        assert_eq!(day15::part1(include_str!("../inputs/day15")), 698);
-        assert_eq!(day15::part2(include_str!("../inputs/day15")), 3022);
+        check(15);
     }
 
     #[test]
     fn test_day_16() {
-        assert_eq!(day16::part1(include_str!("../inputs/day16")), 1038);
-        assert_eq!(day16::part2(include_str!("../inputs/day16")), 246761930504);
+        check(16);
     }
 
     #[test]
     fn test_day_17() {
-        assert_eq!(day17::part1(include_str!("../inputs/day17")), 8911);
-        assert_eq!(day17::part2(include_str!("../inputs/day17")), 4748);
+        check(17);
     }
 
     #[test]
     fn test_day_18() {
-        assert_eq!(day18::part1(include_str!("../inputs/day18")), 3734);
-        assert_eq!(day18::part2(include_str!("../inputs/day18")), 4837);
+        check(18);
+    }
+
+    #[test]
+    #[ignore = "add inputs/day19/input.txt and its answers"]
+    fn test_day_19() {
+        check(19);
+    }
+
+    #[test]
+    #[ignore = "add inputs/day20/input.txt and its answers"]
+    fn test_day_20() {
+        check(20);
+    }
+
+    #[test]
+    #[ignore = "add inputs/day21/input.txt and its answers"]
+    fn test_day_21() {
+        check(21);
+    }
+
+    #[test]
+    #[ignore = "add inputs/day22/input.txt and its answers"]
+    fn test_day_22() {
+        check(22);
+    }
+
+    #[test]
+    #[ignore = "add inputs/day23/input.txt and its answers"]
+    fn test_day_23() {
+        check(23);
+    }
+
+    /// Small generated inputs must be solved without errors, whatever the seed.
+    #[test]
+    fn test_generated_inputs() {
+        // Day 17 tries every velocity whatever the target area, which already takes most of a minute in debug builds
+        for generator in inputgen::GENERATORS.iter().filter(|generator| generator.day != 17) {
+            let puzzle = find_day(generator.day).unwrap();
+            for seed in 0..3 {
+                let input = generator.generate(generator.default_size.min(20), seed);
+                let report = run_day(puzzle, &input, &Part::ALL, 1);
+                let parts = report
+                    .parts
+                    .unwrap_or_else(|error| panic!("day {} seed {}: {}", generator.day, seed, error));
+                for part in parts {
+                    if let Err(error) = part.answer {
+                        panic!("day {} part {} seed {}: {}", generator.day, part.part, seed, error);
+                    }
+                }
+            }
+        }
+    }
+
+    /// The variants of every day must agree with the parts they stand in for on generated inputs.
+    #[test]
+    fn test_variants_agree() {
+        for generator in inputgen::GENERATORS {
+            let puzzle = find_day(generator.day).unwrap();
+            let mismatches = crosscheck::cross_check(puzzle, generator, &SIZES, 0..5);
+            assert!(mismatches.is_empty(), "{}", mismatches[0]);
+        }
+    }
+
+    /// The examples of the puzzle texts must give the answers the texts say, and be the same as the example inputs of
+    /// the same name, which `examples --write` writes.
+    #[test]
+    fn test_doc_examples() {
+        for puzzle in DAYS {
+            let day = puzzle.day();
+            for example in doc_examples(day).unwrap() {
+                let report = run_day(*puzzle, &example.input, &Part::ALL, 1);
+                for (part, verdict) in answers::verify(&report, &Part::ALL, &example.answers) {
+                    assert!(
+                        matches!(verdict, Verdict::Pass | Verdict::Missing { .. }),
+                        "day {} part {} ({} of the puzzle text): {}",
+                        day,
+                        part,
+                        example.name,
+                        verdict
+                    );
+                }
+                let path = input_path(day, &example.name);
+                if path.is_file() {
+                    assert_eq!(
+                        inpututils::read_file(&path).unwrap(),
+                        example.input,
+                        "{}",
+                        path.display()
+                    );
+                }
+            }
+        }
     }
 }
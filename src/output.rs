@@ -0,0 +1,247 @@
+use crate::runner::DayReport;
+use puzzle::{Answer, PuzzleError};
+use serde::Serialize;
+use std::fmt::{self, Display};
+use std::io::{self, Write};
+
+/// How the answers are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// `Day N - Part M: answer` lines, for humans.
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+pub fn parse_format(format: &str) -> Result<Format, String> {
+    match format.trim().to_lowercase().as_str() {
+        "text" => Ok(Format::Text),
+        "json" => Ok(Format::Json),
+        "csv" => Ok(Format::Csv),
+        _ => Err(format!("invalid format `{}`, expected text, json or csv", format)),
+    }
+}
+
+/// How a step went, from best to worst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    Error,
+    Panicked,
+    TimedOut,
+}
+
+impl Status {
+    pub fn of<T>(result: &Result<T, PuzzleError>) -> Status {
+        result.as_ref().err().map_or(Status::Ok, Status::of_error)
+    }
+
+    pub fn of_error(error: &PuzzleError) -> Status {
+        match error {
+            PuzzleError::Panicked { .. } => Status::Panicked,
+            PuzzleError::TimedOut { .. } => Status::TimedOut,
+            _ => Status::Error,
+        }
+    }
+
+    /// The worst status among the steps of a day.
+    pub fn of_day(report: &DayReport) -> Status {
+        match &report.parts {
+            Ok(parts) => parts
+                .iter()
+                .map(|part| Status::of(&part.answer))
+                .max()
+                .unwrap_or(Status::Ok),
+            Err(error) => Status::of_error(error),
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Status::Ok => "ok",
+            Status::Error => "error",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed out",
+        })
+    }
+}
+
+/// One part of one day. Integer answers are numbers, any other answer is text, grids included.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    /// Fastest of the runs, in nanoseconds. Empty when the input could not be parsed.
+    pub duration_ns: Option<u128>,
+    pub status: Status,
+    pub error: Option<String>,
+}
+
+/// A record for each part asked for. When the input could not be parsed, every part gets the parse error.
+pub fn records(reports: &[DayReport], parts: &[puzzle::Part]) -> Vec<Record> {
+    let mut records = Vec::new();
+    for report in reports {
+        match &report.parts {
+            Ok(part_reports) => records.extend(part_reports.iter().map(|part_report| Record {
+                day: report.day,
+                part: part_report.part.number(),
+                answer: part_report.answer.as_ref().ok().cloned(),
+                duration_ns: Some(part_report.timing.min().as_nanos()),
+                status: Status::of(&part_report.answer),
+                error: part_report.answer.as_ref().err().map(|error| error.to_string()),
+            })),
+            Err(error) => records.extend(parts.iter().map(|part| Record {
+                day: report.day,
+                part: part.number(),
+                answer: None,
+                duration_ns: None,
+                status: Status::of_error(error),
+                error: Some(error.to_string()),
+            })),
+        }
+    }
+    records
+}
+
+/// A JSON array of records.
+pub fn write_json(records: &[Record], mut writer: impl Write) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut writer, records)?;
+    writeln!(writer)
+}
+
+/// CSV with a header row; empty cells stand for missing values.
+pub fn write_csv(records: &[Record], writer: impl Write) -> io::Result<()> {
+    let mut csv = csv::Writer::from_writer(writer);
+    for record in records {
+        csv.serialize(record)?;
+    }
+    csv.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::output::{Record, Status};
+    use crate::runner::{DayReport, PartReport};
+    use crate::timing::Timing;
+    use puzzle::{Answer, Part, PuzzleError};
+    use std::time::Duration;
+
+    fn reports() -> Vec<DayReport> {
+        vec![
+            DayReport {
+                day: 13,
+                parse: Timing::from_samples(vec![Duration::from_nanos(10)]),
+                parts: Ok(vec![
+                    PartReport {
+                        part: Part::One,
+                        answer: Ok(Answer::Integer(17)),
+                        timing: Timing::from_samples(vec![Duration::from_nanos(300), Duration::from_nanos(200)]),
+                    },
+                    PartReport {
+                        part: Part::Two,
+                        answer: Ok(Answer::from("#.#\n.#.")),
+                        timing: Timing::from_samples(vec![Duration::from_nanos(400)]),
+                    },
+                ]),
+            },
+            DayReport {
+                day: 14,
+                parse: Timing::from_samples(vec![Duration::from_nanos(10)]),
+                parts: Err(PuzzleError::parse(2, 1, "", "expected a blank line").in_day(14)),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!(crate::output::parse_format("json"), Ok(crate::output::Format::Json));
+        assert_eq!(crate::output::parse_format("CSV"), Ok(crate::output::Format::Csv));
+        assert!(crate::output::parse_format("xml").is_err());
+    }
+
+    #[test]
+    fn test_records() {
+        let records = crate::output::records(&reports(), &[Part::One, Part::Two]);
+        assert_eq!(records.len(), 4);
+        assert_eq!(
+            records[0],
+            Record {
+                day: 13,
+                part: 1,
+                answer: Some(Answer::Integer(17)),
+                duration_ns: Some(200),
+                status: Status::Ok,
+                error: None,
+            }
+        );
+        assert_eq!(
+            records[3],
+            Record {
+                day: 14,
+                part: 2,
+                answer: None,
+                duration_ns: None,
+                status: Status::Error,
+                error: Some("day 14: line 2, column 1: expected a blank line: ``".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_status() {
+        let reports = reports();
+        assert_eq!(Status::of_day(&reports[0]), Status::Ok);
+        assert_eq!(Status::of_day(&reports[1]), Status::Error);
+        let timed_out = DayReport {
+            day: 17,
+            parse: Timing::default(),
+            parts: Err(PuzzleError::timed_out(Duration::from_secs(1)).in_day(17)),
+        };
+        assert_eq!(Status::of_day(&timed_out), Status::TimedOut);
+        assert_eq!(
+            crate::output::records(&[timed_out], &[Part::One])[0].status,
+            Status::TimedOut
+        );
+        assert_eq!(serde_json::to_string(&Status::TimedOut).unwrap(), "\"timed_out\"");
+        assert_eq!(Status::TimedOut.to_string(), "timed out");
+    }
+
+    #[test]
+    fn test_write_json() {
+        let records = crate::output::records(&reports(), &[Part::Two]);
+        let mut output = Vec::new();
+        crate::output::write_json(&records[1..2], &mut output).unwrap();
+        let expected = r##"[
+  {
+    "day": 13,
+    "part": 2,
+    "answer": "#.#\n.#.",
+    "duration_ns": 400,
+    "status": "ok",
+    "error": null
+  }
+]
+"##;
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let records = crate::output::records(&reports(), &[Part::One, Part::Two]);
+        let mut output = Vec::new();
+        crate::output::write_csv(&records, &mut output).unwrap();
+        let expected = "\
+day,part,answer,duration_ns,status,error
+13,1,17,200,ok,
+13,2,\"#.#\n.#.\",400,ok,
+14,1,,,error,\"day 14: line 2, column 1: expected a blank line: ``\"
+14,2,,,error,\"day 14: line 2, column 1: expected a blank line: ``\"
+";
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+}
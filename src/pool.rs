@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Threads to use when none are asked for: one per core.
+pub fn available_threads() -> usize {
+    thread::available_parallelism().map_or(1, usize::from)
+}
+
+/// Runs `work` on every item on a pool of `threads` threads, handing the results to `done` in the order of the items
+/// as soon as they and all the ones before them are ready. A single thread runs everything on the calling thread.
+pub fn run_ordered<T: Sync, R: Send>(
+    items: &[T],
+    threads: usize,
+    work: impl Fn(&T) -> R + Sync,
+    mut done: impl FnMut(R),
+) {
+    if threads <= 1 {
+        items.iter().map(&work).for_each(done);
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..threads.min(items.len()) {
+            let sender = sender.clone();
+            let (next, work) = (&next, &work);
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                if sender.send((index, work(item))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        // Results arriving early wait for the ones before them.
+        let mut pending = BTreeMap::new();
+        let mut emitted = 0;
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&emitted) {
+                done(result);
+                emitted += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_run_ordered() {
+        let items = (0..20u64).collect::<Vec<_>>();
+        for threads in [1, 4, 50] {
+            let mut results = Vec::new();
+            crate::pool::run_ordered(
+                &items,
+                threads,
+                |&item| {
+                    // Later items finish first
+                    thread::sleep(Duration::from_millis(20 - item));
+                    item * 2
+                },
+                |result| results.push(result),
+            );
+            assert_eq!(results, (0..20).map(|item| item * 2).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_run_ordered_without_items() {
+        let mut results: Vec<()> = Vec::new();
+        crate::pool::run_ordered(&[], 4, |&()| (), |result| results.push(result));
+        assert!(results.is_empty());
+    }
+}
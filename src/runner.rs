@@ -0,0 +1,359 @@
+use crate::timing::Timing;
+use crate::{input_path, DAYS};
+use puzzle::{Answer, Parsed, Part, Puzzle, PuzzleError};
+use std::any::Any;
+use std::io;
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Where to read a day's input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// One of the checked in `inputs/dayNN/<name>.txt` files.
+    Named(String),
+    File(PathBuf),
+    Stdin,
+}
+
+impl Input {
+    /// `-` stands for stdin, anything else is a file path.
+    pub fn from_arg(arg: &str) -> Input {
+        if arg == "-" {
+            Input::Stdin
+        } else {
+            Input::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Line endings and trailing whitespace are normalized, see [inpututils::normalize].
+    pub fn load(&self, day: u8) -> io::Result<String> {
+        match self {
+            Input::Named(name) => inpututils::read_file(input_path(day, name)),
+            Input::File(path) => inpututils::read_file(path),
+            Input::Stdin => inpututils::read_from(io::stdin()),
+        }
+    }
+}
+
+/// Registered days within the range, in calendar order.
+pub fn select_days(days: RangeInclusive<u8>) -> Vec<&'static dyn Puzzle> {
+    DAYS.iter()
+        .filter(|puzzle| days.contains(&puzzle.day()))
+        .copied()
+        .collect()
+}
+
+/// Answers and timings of a day.
+#[derive(Debug)]
+pub struct DayReport {
+    pub day: u8,
+    pub parse: Timing,
+    /// The parts that were asked for, or why the input could not be parsed.
+    pub parts: Result<Vec<PartReport>, PuzzleError>,
+}
+
+#[derive(Debug)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Result<Answer, PuzzleError>,
+    pub timing: Timing,
+}
+
+/// How a day is run, [run_day] or [run_day_with_parallel_parts].
+pub type RunDay = fn(&dyn Puzzle, &str, &[Part], usize) -> DayReport;
+
+/// Parses the input and solves the parts, running each step `repeat` times to time it.
+/// A step that panics fails like one returning an error, the other parts still run.
+pub fn run_day(puzzle: &dyn Puzzle, input: &str, parts: &[Part], repeat: usize) -> DayReport {
+    let day = puzzle.day();
+    let (parsed, parse) = Timing::measure(repeat, || catch_panic(day, || puzzle.parse(input)));
+    let parts = parsed.map(|parsed| {
+        parts
+            .iter()
+            .map(|&part| solve_part(day, &*parsed, part, repeat))
+            .collect()
+    });
+    DayReport {
+        day: puzzle.day(),
+        parse,
+        parts,
+    }
+}
+
+/// Same as [run_day], but the parts are solved at the same time, each on its own thread.
+pub fn run_day_with_parallel_parts(puzzle: &dyn Puzzle, input: &str, parts: &[Part], repeat: usize) -> DayReport {
+    let day = puzzle.day();
+    let (parsed, parse) = Timing::measure(repeat, || catch_panic(day, || puzzle.parse(input)));
+    let parts = parsed.map(|parsed| {
+        thread::scope(|scope| {
+            let solving = parts
+                .iter()
+                .map(|&part| {
+                    let parsed = &*parsed;
+                    scope.spawn(move || solve_part(day, parsed, part, repeat))
+                })
+                .collect::<Vec<_>>();
+            solving
+                .into_iter()
+                .map(|part| part.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+                .collect()
+        })
+    });
+    DayReport {
+        day: puzzle.day(),
+        parse,
+        parts,
+    }
+}
+
+/// Runs the day on its own thread and gives up on it after `timeout`, reporting it as timed out. There is no stopping
+/// a thread, so a runaway day keeps running in the background until the process exits.
+pub fn run_day_with_timeout(
+    puzzle: &'static dyn Puzzle,
+    input: &str,
+    parts: &[Part],
+    repeat: usize,
+    timeout: Duration,
+    run: RunDay,
+) -> DayReport {
+    let day = puzzle.day();
+    let (sender, receiver) = mpsc::channel();
+    let (thread_input, thread_parts) = (input.to_string(), parts.to_vec());
+    let spawned = thread::Builder::new().name(format!("day{:02}", day)).spawn(move || {
+        // Nobody is listening any more once the day timed out
+        let _ = sender.send(run(puzzle, &thread_input, &thread_parts, repeat));
+    });
+    if spawned.is_err() {
+        return run(puzzle, input, parts, repeat);
+    }
+    let error = match receiver.recv_timeout(timeout) {
+        Ok(report) => return report,
+        Err(mpsc::RecvTimeoutError::Timeout) => PuzzleError::timed_out(timeout),
+        Err(mpsc::RecvTimeoutError::Disconnected) => PuzzleError::panicked("the day stopped without a report"),
+    };
+    DayReport {
+        day,
+        parse: Timing::default(),
+        parts: Err(error.in_day(day)),
+    }
+}
+
+fn solve_part(day: u8, parsed: &dyn Parsed, part: Part, repeat: usize) -> PartReport {
+    let (answer, timing) = Timing::measure(repeat, || catch_panic(day, || parsed.solve(part)));
+    PartReport { part, answer, timing }
+}
+
+/// Turns a panic of `step` into an error of the day.
+pub fn catch_panic<T>(day: u8, step: impl FnOnce() -> Result<T, PuzzleError>) -> Result<T, PuzzleError> {
+    panic::catch_unwind(AssertUnwindSafe(step))
+        .unwrap_or_else(|payload| Err(PuzzleError::panicked(panic_message(&*payload)).in_day(day)))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Parses a day range like `3..7`, `3..=7` or `3-7` (all inclusive), or a single day like `5`.
+pub fn parse_day_range(range: &str) -> Result<RangeInclusive<u8>, String> {
+    let parse_day = |day: &str| day.trim().parse::<u8>().map_err(|_| format!("invalid day `{}`", day));
+    let (start, end) = if let Some((start, end)) = range.split_once("..=") {
+        (parse_day(start)?, parse_day(end)?)
+    } else if let Some((start, end)) = range.split_once("..") {
+        (parse_day(start)?, parse_day(end)?)
+    } else if let Some((start, end)) = range.split_once('-') {
+        (parse_day(start)?, parse_day(end)?)
+    } else {
+        let day = parse_day(range)?;
+        (day, day)
+    };
+    if start > end {
+        return Err(format!("empty day range `{}`", range));
+    }
+    Ok(start..=end)
+}
+
+/// Parses a duration like `500ms`, `10s` or `2m`; a bare number is in seconds.
+pub fn parse_timeout(timeout: &str) -> Result<Duration, String> {
+    let timeout = timeout.trim();
+    let (number, unit) = timeout.split_at(timeout.find(|c: char| c.is_alphabetic()).unwrap_or(timeout.len()));
+    let invalid = || format!("invalid timeout `{}`, expected e.g. 500ms, 10s or 2m", timeout);
+    let number = number.trim().parse::<f64>().map_err(|_| invalid())?;
+    let seconds = match unit {
+        "ms" => number / 1000.0,
+        "" | "s" => number,
+        "m" => number * 60.0,
+        _ => return Err(invalid()),
+    };
+    Duration::try_from_secs_f64(seconds)
+        .ok()
+        .filter(|timeout| !timeout.is_zero())
+        .ok_or_else(invalid)
+}
+
+pub fn parse_part(part: &str) -> Result<Part, String> {
+    match part.trim() {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("invalid part `{}`, expected 1 or 2", part)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::runner::run_day;
+    use puzzle::{Answer, Part, PuzzleError, Solution};
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_day_range() {
+        assert_eq!(crate::runner::parse_day_range("3..7"), Ok(3..=7));
+        assert_eq!(crate::runner::parse_day_range("3..=7"), Ok(3..=7));
+        assert_eq!(crate::runner::parse_day_range("3-7"), Ok(3..=7));
+        assert_eq!(crate::runner::parse_day_range("5"), Ok(5..=5));
+        assert!(crate::runner::parse_day_range("7..3").is_err());
+        assert!(crate::runner::parse_day_range("a..3").is_err());
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!(crate::runner::parse_part("1"), Ok(Part::One));
+        assert_eq!(crate::runner::parse_part("2"), Ok(Part::Two));
+        assert!(crate::runner::parse_part("3").is_err());
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        assert_eq!(crate::runner::parse_timeout("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(crate::runner::parse_timeout("10s"), Ok(Duration::from_secs(10)));
+        assert_eq!(crate::runner::parse_timeout("1.5"), Ok(Duration::from_millis(1500)));
+        assert_eq!(crate::runner::parse_timeout("2m"), Ok(Duration::from_secs(120)));
+        assert!(crate::runner::parse_timeout("0s").is_err());
+        assert!(crate::runner::parse_timeout("-1s").is_err());
+        assert!(crate::runner::parse_timeout("10h").is_err());
+    }
+
+    #[test]
+    fn test_select_days() {
+        let days = crate::runner::select_days(3..=7)
+            .iter()
+            .map(|puzzle| puzzle.day())
+            .collect::<Vec<_>>();
+        assert_eq!(days, vec![3, 4, 5, 6, 7]);
+        assert!(crate::runner::select_days(24..=25).is_empty());
+    }
+
+    #[test]
+    fn test_run_day() {
+        let puzzle = crate::find_day(1).unwrap();
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+        let report = crate::runner::run_day(puzzle, input, &[Part::Two], 3);
+        assert_eq!(report.day, 1);
+        let parts = report.parts.unwrap();
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].part, Part::Two);
+        assert_eq!(parts[0].answer, Ok(Answer::Integer(5)));
+
+        // Bad input is reported by parsing, before any part runs
+        let report = crate::runner::run_day(puzzle, "199\nx", &Part::ALL, 1);
+        assert!(matches!(report.parts, Err(PuzzleError::Parse { line: 2, .. })));
+    }
+
+    #[test]
+    fn test_run_day_with_parallel_parts() {
+        let puzzle = crate::find_day(1).unwrap();
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+        let report = crate::runner::run_day_with_parallel_parts(puzzle, input, &Part::ALL, 2);
+        let answers = report
+            .parts
+            .unwrap()
+            .into_iter()
+            .map(|part| (part.part, part.answer))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            answers,
+            vec![(Part::One, Ok(Answer::Integer(7))), (Part::Two, Ok(Answer::Integer(5)))]
+        );
+    }
+
+    #[test]
+    fn test_input_from_arg() {
+        assert_eq!(crate::runner::Input::from_arg("-"), crate::runner::Input::Stdin);
+        assert_eq!(
+            crate::runner::Input::from_arg("big.txt"),
+            crate::runner::Input::File("big.txt".into())
+        );
+    }
+
+    /// Panics on `panic` when parsing, always in part 2, and never finishes part 1 of `slow`.
+    struct Broken;
+
+    impl Solution for Broken {
+        const DAY: u8 = 25;
+
+        type Input = String;
+        type Answer1 = String;
+        type Answer2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+            if input == "panic" {
+                panic!("cannot parse {}", input);
+            }
+            Ok(input.to_string())
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+            if input == "slow" {
+                loop {
+                    thread::sleep(Duration::from_millis(10));
+                }
+            }
+            Ok(input.clone())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+            let numbers: Vec<u8> = Vec::new();
+            Ok(format!("{}{}", input, numbers[input.len()]))
+        }
+    }
+
+    static BROKEN: Broken = Broken;
+
+    #[test]
+    fn test_panics_are_isolated() {
+        let report = crate::runner::run_day(&BROKEN, "fine", &Part::ALL, 1);
+        let parts = report.parts.unwrap();
+        assert_eq!(parts[0].answer, Ok(Answer::from("fine")));
+        assert_eq!(
+            parts[1].answer,
+            Err(PuzzleError::panicked("index out of bounds: the len is 0 but the index is 4").in_day(25))
+        );
+
+        let report = crate::runner::run_day_with_parallel_parts(&BROKEN, "panic", &Part::ALL, 1);
+        assert_eq!(
+            report.parts.err(),
+            Some(PuzzleError::panicked("cannot parse panic").in_day(25))
+        );
+    }
+
+    #[test]
+    fn test_run_day_with_timeout() {
+        let timeout = Duration::from_millis(50);
+        let report = crate::runner::run_day_with_timeout(&BROKEN, "slow", &Part::ALL, 1, timeout, run_day);
+        assert_eq!(report.day, 25);
+        assert_eq!(report.parts.err(), Some(PuzzleError::timed_out(timeout).in_day(25)));
+
+        let report = crate::runner::run_day_with_timeout(&BROKEN, "fast", &[Part::One], 1, timeout, run_day);
+        assert_eq!(report.parts.unwrap()[0].answer, Ok(Answer::from("fast")));
+    }
+}
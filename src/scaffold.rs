@@ -0,0 +1,253 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = r#"[package]
+name = "dayNN"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+inpututils = { path = "../inpututils" }
+puzzle = { path = "../puzzle" }
+"#;
+
+const LIB_RS: &str = r#"use puzzle::PuzzleError;
+
+/// --- Day D ---
+/// Paste the puzzle text here, with its examples fenced in as ```` ```text example part1:... part2:... ```` blocks so
+/// the main test suite solves them.
+pub fn part1(input: &[String]) -> Result<usize, PuzzleError> {
+    Err(PuzzleError::no_solution(format!("part 1 isn't solved yet ({} lines)", input.len())))
+}
+
+/// --- Part Two ---
+pub fn part2(input: &[String]) -> Result<usize, PuzzleError> {
+    Err(PuzzleError::no_solution(format!("part 2 isn't solved yet ({} lines)", input.len())))
+}
+
+pub fn parse(input: &str) -> Result<Vec<String>, PuzzleError> {
+    inpututils::parse_lines(input)
+}
+
+pub struct DayNN;
+
+impl puzzle::Solution for DayNN {
+    const DAY: u8 = D;
+
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, PuzzleError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, PuzzleError> {
+        part2(input)
+    }
+}
+"#;
+
+const MAIN_TEST: &str = r#"    #[test]
+    #[ignore = "add inputs/dayNN/input.txt and its answers"]
+    fn test_day_NN() {
+        check(D);
+    }
+"#;
+
+/// Fills a template in: `NN` is the zero padded day, `D` the day.
+fn fill(template: &str, day: u8) -> String {
+    template
+        .replace("NN", &format!("{:02}", day))
+        .replace(" D ", &format!(" {} ", day))
+        .replace(" D;", &format!(" {};", day))
+        .replace("(D)", &format!("({})", day))
+}
+
+/// Creates the `dayNN` crate of a day in the project at `root`, with a solution that doesn't solve anything yet, and
+/// registers it: as a dependency in `Cargo.toml`, in `DAYS` and with a test of the main test suite. The benchmarks go
+/// through `DAYS`, so they pick it up from there. Returns the files created or changed.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("there is no day {} in the calendar", day));
+    }
+    let name = format!("day{:02}", day);
+    let crate_dir = root.join(&name);
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+
+    // Check every file can be changed before touching anything
+    let cargo_toml = root.join("Cargo.toml");
+    let lib_rs = root.join("src/lib.rs");
+    let main_rs = root.join("src/main.rs");
+    let read =
+        |path: &Path| fs::read_to_string(path).map_err(|error| format!("cannot read {}: {}", path.display(), error));
+    let changes = [
+        (
+            cargo_toml.clone(),
+            insert_in_order(
+                &read(&cargo_toml)?,
+                day,
+                &format!("{} = {{ path = \"{}\" }}\n", name, name),
+                |line| line.strip_prefix("day")?.split(' ').next()?.parse().ok(),
+            )
+            .ok_or("no day among the dependencies of Cargo.toml")?,
+        ),
+        (
+            lib_rs.clone(),
+            insert_in_order(
+                &read(&lib_rs)?,
+                day,
+                &format!("    &{}::Day{:02},\n", name, day),
+                |line| line.strip_prefix("    &day")?.split("::").next()?.parse().ok(),
+            )
+            .ok_or("no day in DAYS of src/lib.rs")?,
+        ),
+        (
+            main_rs.clone(),
+            insert_test(&read(&main_rs)?, day, &fill(MAIN_TEST, day)).ok_or("no test_day_NN in src/main.rs")?,
+        ),
+    ];
+
+    let write = |path: &Path, contents: &str| {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|error| format!("cannot create {}: {}", dir.display(), error))?;
+        }
+        fs::write(path, contents).map_err(|error| format!("cannot write {}: {}", path.display(), error))
+    };
+    let mut files = vec![crate_dir.join("Cargo.toml"), crate_dir.join("src/lib.rs")];
+    write(&files[0], &fill(CARGO_TOML, day))?;
+    write(&files[1], &fill(LIB_RS, day))?;
+    for (path, contents) in changes {
+        write(&path, &contents)?;
+        files.push(path);
+    }
+    Ok(files)
+}
+
+/// Inserts `line` among the consecutive lines `day_of` finds a day in, keeping them sorted by day.
+fn insert_in_order(text: &str, day: u8, line: &str, day_of: impl Fn(&str) -> Option<u8>) -> Option<String> {
+    let lines = text.split_inclusive('\n').collect::<Vec<_>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| day_of(line).map(|day| (i, day)))
+        .collect::<Vec<_>>();
+    let (first, _) = *days.first()?;
+    let position = days
+        .iter()
+        .find(|&&(_, other)| other > day)
+        .map_or_else(|| days.last().unwrap().0 + 1, |&(i, _)| i)
+        .max(first);
+    let mut lines = lines.iter().map(|line| line.to_string()).collect::<Vec<_>>();
+    lines.insert(position, line.to_string());
+    Some(lines.concat())
+}
+
+/// Inserts a `test_day_NN` test of the main test suite next to the others, keeping them sorted by day. Tests are told
+/// apart by the blank lines between them, so their attributes and doc comments move with them.
+fn insert_test(text: &str, day: u8, test: &str) -> Option<String> {
+    let tests = text
+        .match_indices("\n    fn test_day_")
+        .filter_map(|(function, pattern)| {
+            let day = text[function + pattern.len()..].split('(').next()?.parse::<u8>().ok()?;
+            let start = text[..function].rfind("\n\n")? + 2;
+            let end = function + text[function..].find("\n    }\n")? + "\n    }\n".len();
+            Some((start, end, day))
+        })
+        .collect::<Vec<_>>();
+    let &(_, last_end, _) = tests.last()?;
+    let mut text = text.to_string();
+    match tests.iter().find(|&&(_, _, other)| other > day) {
+        Some(&(start, _, _)) => text.insert_str(start, &format!("{}\n", test)),
+        None => text.insert_str(last_end, &format!("\n{}", test)),
+    }
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::{fill, insert_in_order, insert_test, new_day, MAIN_TEST};
+    use std::fs;
+
+    #[test]
+    fn test_fill() {
+        assert_eq!(
+            fill(MAIN_TEST, 7),
+            "    #[test]\n    #[ignore = \"add inputs/day07/input.txt and its answers\"]\n    fn test_day_07() {\n        \
+             check(7);\n    }\n"
+        );
+    }
+
+    #[test]
+    fn test_insert_in_order() {
+        let day_of = |line: &str| line.strip_prefix("day")?.split(' ').next()?.parse().ok();
+        let toml = "[dependencies]\npuzzle = 1\nday01 = 1\nday03 = 1\n\n[dev-dependencies]\n";
+        assert_eq!(
+            insert_in_order(toml, 2, "day02 = 1\n", day_of).unwrap(),
+            "[dependencies]\npuzzle = 1\nday01 = 1\nday02 = 1\nday03 = 1\n\n[dev-dependencies]\n"
+        );
+        assert_eq!(
+            insert_in_order(toml, 19, "day19 = 1\n", day_of).unwrap(),
+            "[dependencies]\npuzzle = 1\nday01 = 1\nday03 = 1\nday19 = 1\n\n[dev-dependencies]\n"
+        );
+        assert_eq!(insert_in_order("[dependencies]\n", 1, "day01 = 1\n", day_of), None);
+    }
+
+    #[test]
+    fn test_insert_test() {
+        let main = "mod tests {\n    #[test]\n    fn test_day_01() {\n        check(1);\n    }\n\n    #[test]\n    fn \
+                    test_day_03() {\n        check(3);\n    }\n\n    #[test]\n    fn test_generated() {}\n}\n";
+        let inserted = insert_test(main, 2, "    #[test]\n    fn test_day_02() {}\n").unwrap();
+        assert!(inserted
+            .contains("check(1);\n    }\n\n    #[test]\n    fn test_day_02() {}\n\n    #[test]\n    fn test_day_03"));
+        let inserted = insert_test(main, 19, "    #[test]\n    fn test_day_19() {}\n").unwrap();
+        assert!(inserted.contains(
+            "check(3);\n    }\n\n    #[test]\n    fn test_day_19() {}\n\n    #[test]\n    fn test_generated"
+        ));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        for file in ["Cargo.toml", "src/lib.rs", "src/main.rs"] {
+            fs::copy(file, root.join(file)).unwrap();
+        }
+
+        // The last days of the calendar, the ones most likely not to be solved yet
+        let files = new_day(&root, 25).unwrap();
+        assert_eq!(files.len(), 5);
+        let lib = fs::read_to_string(root.join("day25/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day25;\n\nimpl puzzle::Solution for Day25 {\n    const DAY: u8 = 25;"));
+        assert!(lib.contains("/// --- Day 25 ---"));
+        let main = fs::read_to_string(root.join("src/main.rs")).unwrap();
+        assert!(main.contains("fn test_day_25() {\n        check(25);\n    }\n"));
+
+        // The new test is ignored until the day has an input, the next one still goes before it
+        new_day(&root, 24).unwrap();
+        let toml = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(toml.contains("day24 = { path = \"day24\" }\nday25 = { path = \"day25\" }\n"));
+        let days = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        assert!(days.contains("    &day24::Day24,\n    &day25::Day25,\n];"));
+        let main = fs::read_to_string(root.join("src/main.rs")).unwrap();
+        assert!(main.find("check(24)").unwrap() < main.find("check(25)").unwrap());
+
+        assert_eq!(
+            new_day(&root, 25),
+            Err(format!("{} already exists", root.join("day25").display()))
+        );
+        assert_eq!(
+            new_day(&root, 26),
+            Err("there is no day 26 in the calendar".to_string())
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
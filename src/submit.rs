@@ -0,0 +1,249 @@
+use crate::answers::Hint;
+use crate::fetch::{Fetcher, USER_AGENT};
+use puzzle::{Answer, Part};
+use std::fmt::{self, Display};
+use std::time::Duration;
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Right,
+    Wrong {
+        hint: Option<Hint>,
+    },
+    /// An answer was submitted too recently, nothing was checked.
+    TooRecent {
+        wait: Option<Duration>,
+    },
+    /// The part was already solved, or isn't unlocked yet.
+    WrongLevel,
+    /// Anything else, as the text of the page.
+    Unknown(String),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Right => write!(f, "right answer"),
+            Outcome::Wrong { hint: Some(hint) } => write!(f, "wrong answer, {}", hint),
+            Outcome::Wrong { hint: None } => write!(f, "wrong answer"),
+            Outcome::TooRecent { wait: Some(wait) } => write!(f, "answered too recently, try again in {:?}", wait),
+            Outcome::TooRecent { wait: None } => write!(f, "answered too recently, try again later"),
+            Outcome::WrongLevel => write!(f, "this part is already solved or not unlocked yet"),
+            Outcome::Unknown(text) => write!(f, "unexpected response: {}", text),
+        }
+    }
+}
+
+impl Fetcher {
+    pub fn answer_url(&self, day: u8) -> String {
+        format!("{}/2021/day/{}/answer", self.base_url.trim_end_matches('/'), day)
+    }
+
+    /// Posts an answer, waiting first if the last request was too recent.
+    pub fn submit(&self, day: u8, part: Part, answer: &Answer) -> Result<Outcome, String> {
+        self.wait_for_rate_limit();
+        let url = self.answer_url(day);
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .send_form(&[("level", &part.to_string()), ("answer", &answer.to_string())]);
+        self.record_request();
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map(|page| parse_response(&page))
+                .map_err(|error| format!("cannot read the response of {}: {}", url, error)),
+            Err(ureq::Error::Status(status, response)) => {
+                let message = response.into_string().unwrap_or_default();
+                Err(format!("{} answered {}: {}", url, status, message.trim()))
+            }
+            Err(error) => Err(format!("cannot submit to {}: {}", url, error)),
+        }
+    }
+}
+
+/// Reads the outcome from the `<article>` of the page the site answers with.
+pub fn parse_response(page: &str) -> Outcome {
+    let article = match (page.find("<article"), page.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &page[start..end],
+        _ => page,
+    };
+    let text = text_of(article);
+    if text.contains("That's the right answer") {
+        Outcome::Right
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        Outcome::Wrong { hint }
+    } else if text.contains("You gave an answer too recently") {
+        Outcome::TooRecent { wait: wait_time(&text) }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unknown(text)
+    }
+}
+
+/// The text of some HTML, tags removed and whitespace collapsed.
+fn text_of(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads `You have 1m 23s left to wait`.
+fn wait_time(text: &str) -> Option<Duration> {
+    let text = text.to_lowercase();
+    let start = text.rfind("you have ")? + "you have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    text[start..end]
+        .split_whitespace()
+        .try_fold(Duration::ZERO, |wait, amount| {
+            let seconds = if let Some(minutes) = amount.strip_suffix('m') {
+                minutes.parse::<u64>().ok()? * 60
+            } else {
+                amount.strip_suffix('s')?.parse::<u64>().ok()?
+            };
+            Some(wait + Duration::from_secs(seconds))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::Hint;
+    use crate::fetch::Fetcher;
+    use crate::submit::{parse_response, Outcome};
+    use puzzle::{Answer, Part};
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::thread;
+    use std::time::Duration;
+
+    fn page(article: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            article
+        )
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(&page(
+                "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."
+            )),
+            Outcome::Right
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the \
+                 full input data. Please wait one minute before trying again. <a href=\"/2021/day/17\">[Return to Day \
+                 17]</a>"
+            )),
+            Outcome::Wrong {
+                hint: Some(Hint::TooHigh)
+            }
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer; your answer is too low.")),
+            Outcome::Wrong {
+                hint: Some(Hint::TooLow)
+            }
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer.  If you're stuck, ...")),
+            Outcome::Wrong { hint: None }
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  \
+                 You have 1m 23s left to wait."
+            )),
+            Outcome::TooRecent {
+                wait: Some(Duration::from_secs(83))
+            }
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Outcome::WrongLevel
+        );
+        assert_eq!(
+            parse_response("<p>Maintenance</p>"),
+            Outcome::Unknown("Maintenance".to_string())
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        // Stands in for the puzzle server, sending back the request line and the form it got.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            reader.read_line(&mut request).unwrap();
+            let mut length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut form = vec![0; length];
+            reader.read_exact(&mut form).unwrap();
+            let body = page("That's the right answer!");
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+            (request.trim().to_string(), String::from_utf8(form).unwrap())
+        });
+
+        let dir = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        let fetcher = Fetcher {
+            base_url,
+            session: "secret".to_string(),
+            rate_limit: Duration::ZERO,
+            stamp: dir.join(".last-fetch"),
+        };
+        assert_eq!(
+            fetcher.submit(17, Part::Two, &Answer::Integer(4748)),
+            Ok(Outcome::Right)
+        );
+        assert_eq!(
+            server.join().unwrap(),
+            (
+                "POST /2021/day/17/answer HTTP/1.1".to_string(),
+                "level=2&answer=4748".to_string()
+            )
+        );
+        let _ = std::fs::remove_dir_all(PathBuf::from(&dir));
+    }
+}
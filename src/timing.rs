@@ -0,0 +1,314 @@
+use crate::output::Status;
+use crate::runner::DayReport;
+use puzzle::Part;
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+/// Durations of the repeated runs of a single step.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Timing {
+    samples: Vec<Duration>,
+}
+
+impl Timing {
+    pub fn from_samples(samples: Vec<Duration>) -> Timing {
+        Timing { samples }
+    }
+
+    /// Runs `step` `repeat` times (at least once), returning the last result.
+    pub fn measure<T>(repeat: usize, mut step: impl FnMut() -> T) -> (T, Timing) {
+        let mut samples = Vec::with_capacity(repeat);
+        let mut result = None;
+        for _ in 0..repeat.max(1) {
+            let start = Instant::now();
+            let value = step();
+            samples.push(start.elapsed());
+            // Dropping the previous result outside of the measured time
+            result = Some(value);
+        }
+        (result.unwrap(), Timing { samples })
+    }
+
+    /// Time spent over all the runs.
+    pub fn total(&self) -> Duration {
+        self.samples.iter().sum()
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples.iter().min().copied().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut samples = self.samples.clone();
+        samples.sort();
+        match samples.len() {
+            0 => Duration::ZERO,
+            n if n % 2 == 1 => samples[n / 2],
+            n => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+        }
+    }
+}
+
+/// A cell of the report: min and median, added up over several steps.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Cell {
+    min: Duration,
+    median: Duration,
+}
+
+impl Cell {
+    fn of(timing: &Timing) -> Cell {
+        Cell {
+            min: timing.min(),
+            median: timing.median(),
+        }
+    }
+
+    fn add(self, other: Cell) -> Cell {
+        Cell {
+            min: self.min + other.min,
+            median: self.median + other.median,
+        }
+    }
+
+    fn format(self, repeat: usize) -> String {
+        if repeat > 1 {
+            format!("{:.2?} / {:.2?}", self.min, self.median)
+        } else {
+            format!("{:.2?}", self.min)
+        }
+    }
+}
+
+fn row(title: String, cells: [Option<Cell>; 4], repeat: usize) -> Vec<String> {
+    let cells = cells.map(|cell| cell.map_or(String::new(), |cell| cell.format(repeat)));
+    std::iter::once(title).chain(cells).collect()
+}
+
+/// Summary table with the parse, part 1, part 2 and total time of each day, plus a total row.
+/// Steps that failed or didn't run are left blank and don't count towards the totals. When a day failed, a last column
+/// tells how each day went: ok, error, panicked or timed out.
+pub fn report(days: &[DayReport], repeat: usize) -> String {
+    let with_status = days.iter().any(|day| Status::of_day(day) != Status::Ok);
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut totals: [Option<Cell>; 4] = [None; 4];
+    for day in days {
+        // A day that timed out has no timings at all
+        let parse = (!day.parse.samples.is_empty()).then(|| Cell::of(&day.parse));
+        let mut cells: [Option<Cell>; 4] = [parse, None, None, None];
+        if let Ok(parts) = &day.parts {
+            for part in parts.iter().filter(|part| part.answer.is_ok()) {
+                let column = match part.part {
+                    Part::One => 1,
+                    Part::Two => 2,
+                };
+                cells[column] = Some(Cell::of(&part.timing));
+            }
+        }
+        cells[3] = cells[..3].iter().flatten().copied().reduce(Cell::add);
+        for (total, cell) in totals.iter_mut().zip(cells) {
+            if let Some(cell) = cell {
+                *total = Some(total.unwrap_or_default().add(cell));
+            }
+        }
+        let mut row = row(day.day.to_string(), cells, repeat);
+        if with_status {
+            row.push(Status::of_day(day).to_string());
+        }
+        rows.push(row);
+    }
+    let mut total_row = row("Total".to_string(), totals, repeat);
+    if with_status {
+        let failed = days.iter().filter(|day| Status::of_day(day) != Status::Ok).count();
+        total_row.push(format!("{} failed", failed));
+    }
+    rows.push(total_row);
+
+    let mut header = ["Day", "Parse", "Part 1", "Part 2", "Total"].map(String::from).to_vec();
+    if with_status {
+        header.push("Status".to_string());
+    }
+    let mut widths = header.iter().map(|title| title.len()).collect::<Vec<_>>();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    if repeat > 1 {
+        writeln!(table, "Min / median of {} runs", repeat).unwrap();
+    }
+    let total_row = rows.len();
+    for (i, row) in std::iter::once(&header).chain(&rows).enumerate() {
+        if i == 1 || i == total_row {
+            let separator = widths.iter().map(|&width| "-".repeat(width)).collect::<Vec<_>>();
+            writeln!(table, "{}", separator.join("-+-")).unwrap();
+        }
+        let line = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(column, (cell, &width))| {
+                if column == 0 || column == 5 {
+                    format!("{:<width$}", cell, width = width)
+                } else {
+                    format!("{:>width$}", cell, width = width)
+                }
+            })
+            .collect::<Vec<_>>();
+        writeln!(table, "{}", line.join(" | ").trim_end()).unwrap();
+    }
+    table
+}
+
+/// Time spent in every step of every run, whichever thread it ran on, failed steps included.
+pub fn cpu_time(days: &[DayReport]) -> Duration {
+    days.iter()
+        .map(|day| {
+            let parts = day
+                .parts
+                .iter()
+                .flatten()
+                .map(|part| part.timing.total())
+                .sum::<Duration>();
+            day.parse.total() + parts
+        })
+        .sum()
+}
+
+/// How long the whole run took against the time spent in the steps, which only differ much when running in parallel.
+pub fn wall_clock_summary(wall: Duration, days: &[DayReport], threads: usize) -> String {
+    let cpu = cpu_time(days);
+    let speedup = if wall.is_zero() {
+        1.0
+    } else {
+        cpu.as_secs_f64() / wall.as_secs_f64()
+    };
+    format!(
+        "Wall clock {:.2?}, summed CPU time {:.2?} on {} thread{} ({:.2}x)\n",
+        wall,
+        cpu,
+        threads,
+        if threads == 1 { "" } else { "s" },
+        speedup
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::runner::{DayReport, PartReport};
+    use crate::timing::Timing;
+    use puzzle::{Answer, Part, PuzzleError};
+    use std::time::Duration;
+
+    fn millis(samples: &[u64]) -> Timing {
+        Timing::from_samples(samples.iter().map(|&ms| Duration::from_millis(ms)).collect())
+    }
+
+    #[test]
+    fn test_min_median() {
+        let timing = millis(&[5, 1, 3]);
+        assert_eq!(timing.min(), Duration::from_millis(1));
+        assert_eq!(timing.median(), Duration::from_millis(3));
+        assert_eq!(millis(&[4, 1, 3, 2]).median(), Duration::from_micros(2500));
+        assert_eq!(Timing::default().median(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut calls = 0;
+        let (result, timing) = Timing::measure(3, || {
+            calls += 1;
+            calls
+        });
+        assert_eq!(result, 3);
+        assert_eq!(timing.samples.len(), 3);
+        assert_eq!(Timing::measure(0, || ()).1.samples.len(), 1);
+    }
+
+    #[test]
+    fn test_report() {
+        let days = vec![
+            DayReport {
+                day: 1,
+                parse: millis(&[1]),
+                parts: Ok(vec![
+                    PartReport {
+                        part: Part::One,
+                        answer: Ok(Answer::Integer(1482)),
+                        timing: millis(&[2]),
+                    },
+                    PartReport {
+                        part: Part::Two,
+                        answer: Err(PuzzleError::no_solution("nothing to compare")),
+                        timing: millis(&[30]),
+                    },
+                ]),
+            },
+            DayReport {
+                day: 12,
+                parse: millis(&[10]),
+                parts: Err(PuzzleError::parse(1, 1, "", "empty")),
+            },
+            DayReport {
+                day: 17,
+                parse: Timing::default(),
+                parts: Err(PuzzleError::timed_out(Duration::from_secs(10))),
+            },
+        ];
+        let expected = "\
+Day   |   Parse | Part 1 | Part 2 |   Total | Status
+------+---------+--------+--------+---------+----------
+1     |  1.00ms | 2.00ms |        |  3.00ms | error
+12    | 10.00ms |        |        | 10.00ms | error
+17    |         |        |        |         | timed out
+------+---------+--------+--------+---------+----------
+Total | 11.00ms | 2.00ms |        | 13.00ms | 3 failed
+";
+        assert_eq!(crate::timing::report(&days, 1), expected);
+    }
+
+    #[test]
+    fn test_wall_clock_summary() {
+        let days = vec![
+            DayReport {
+                day: 1,
+                parse: millis(&[1, 1]),
+                parts: Ok(vec![PartReport {
+                    part: Part::One,
+                    answer: Err(PuzzleError::no_solution("nothing to compare")),
+                    timing: millis(&[3, 5]),
+                }]),
+            },
+            DayReport {
+                day: 2,
+                parse: millis(&[10, 20]),
+                parts: Err(PuzzleError::parse(1, 1, "", "empty")),
+            },
+        ];
+        assert_eq!(crate::timing::cpu_time(&days), Duration::from_millis(40));
+        assert_eq!(
+            crate::timing::wall_clock_summary(Duration::from_millis(20), &days, 4),
+            "Wall clock 20.00ms, summed CPU time 40.00ms on 4 threads (2.00x)\n"
+        );
+    }
+
+    #[test]
+    fn test_report_with_repeats() {
+        let days = vec![DayReport {
+            day: 7,
+            parse: millis(&[1, 3, 2]),
+            parts: Ok(vec![]),
+        }];
+        let expected = "\
+Min / median of 3 runs
+Day   |           Parse | Part 1 | Part 2 |           Total
+------+-----------------+--------+--------+----------------
+7     | 1.00ms / 2.00ms |        |        | 1.00ms / 2.00ms
+------+-----------------+--------+--------+----------------
+Total | 1.00ms / 2.00ms |        |        | 1.00ms / 2.00ms
+";
+        assert_eq!(crate::timing::report(&days, 3), expected);
+    }
+}